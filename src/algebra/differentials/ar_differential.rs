@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::algebra::{ar_product, Alpha, MultiVector, Term, AR};
+use crate::algebra::{ar_product, full, Alpha, MultiVector, Term, AR};
 
 #[derive(Hash, Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Serialize, Deserialize)]
 pub struct ArDifferential {
@@ -17,14 +17,48 @@ impl ArDifferential {
     }
 
     pub fn left_apply(&self, mvec: &MultiVector) -> MultiVector {
-        self.apply(mvec, ApplyFrom::Left)
+        self.apply(mvec, Side::Left)
     }
 
     pub fn right_apply(&self, mvec: &MultiVector) -> MultiVector {
-        self.apply(mvec, ApplyFrom::Right)
+        self.apply(mvec, Side::Right)
     }
 
-    fn apply(&self, mvec: &MultiVector, side: ApplyFrom) -> MultiVector {
+    /// Apply this differential from the given side, keeping only the resulting terms of
+    /// the given grade (0 for ap through 4 for a0123). Terms of other grades are dropped
+    /// as they are produced rather than in a separate projection.
+    pub fn apply_and_project(&self, mvec: &MultiVector, grade: usize, side: Side) -> MultiVector {
+        MultiVector::from_terms(
+            mvec.as_terms()
+                .iter()
+                .flat_map(|t| self.wrt.iter().map(move |w| term_partial(t, w, side)))
+                .filter(|t| t.form().grade() == grade)
+                .collect(),
+        )
+    }
+
+    /// The dual of this differential: each of the alphas it differentiates with respect to
+    /// is replaced by its dual (see [`AR::dual`]).
+    pub fn dual(&self) -> ArDifferential {
+        ArDifferential {
+            wrt: self.wrt.iter().map(|w| w.dual()).collect(),
+        }
+    }
+
+    /// The failure of this differential to commute with mvec: the difference between
+    /// applying it from the left and from the right, simplified.
+    pub fn commutator_with(&self, mvec: &MultiVector) -> MultiVector {
+        let mut res = self.left_apply(mvec) - self.right_apply(mvec);
+        res.simplify();
+        res
+    }
+
+    /// Consume this differential, returning a closure that applies it from the left
+    pub fn into_fn(self) -> impl Fn(MultiVector) -> MultiVector {
+        move |mvec| self.left_apply(&mvec)
+    }
+
+    fn apply(&self, mvec: &MultiVector, side: Side) -> MultiVector {
         MultiVector::from_terms(
             mvec.as_terms()
                 .iter()
@@ -40,6 +74,40 @@ impl ArDifferential {
     }
 }
 
+/// Check whether field satisfies the equation `operator field = 0`, with the operator
+/// applied from the left and the result simplified before checking.
+pub fn satisfies_field_equation(field: &MultiVector, operator: &ArDifferential) -> bool {
+    operator.left_apply(field).is_zero()
+}
+
+/// The failure of op to satisfy the product rule: `D(AB) - (DA)B - A(DB)`, simplified.
+/// D(AB) is formed by applying the product rule to the Xi values of each pair of terms, so
+/// the defect isolates the effect of non-commutativity: it is zero whenever every alpha
+/// that op differentiates with respect to commutes with every alpha in a.
+pub fn leibniz_defect(op: &ArDifferential, a: &MultiVector, b: &MultiVector) -> MultiVector {
+    let mut d_ab = vec![];
+    for ta in a.iter() {
+        for tb in b.iter() {
+            for w in op.wrt.iter() {
+                d_ab.push(term_partial(ta, w, Side::Left).form_product_with(tb));
+
+                let mut db = tb.clone();
+                db.add_partial(w);
+                let mut t = ta.form_product_with(&db);
+                t.set_alpha(ar_product(w, &t.alpha()));
+                d_ab.push(t);
+            }
+        }
+    }
+
+    let da_b: MultiVector = full(&op.left_apply(a), b);
+    let a_db: MultiVector = full(a, &op.left_apply(b));
+    let mut defect = MultiVector::from_terms(d_ab) - da_b - a_db;
+    defect.simplify();
+
+    defect
+}
+
 impl fmt::Display for ArDifferential {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -54,16 +122,17 @@ impl fmt::Display for ArDifferential {
     }
 }
 
+/// The side of a MultiVector that an [`ArDifferential`] is applied from
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
-enum ApplyFrom {
+pub enum Side {
     Left,
     Right,
 }
 
-fn term_partial(term: &Term, wrt: &Alpha, side: ApplyFrom) -> Term {
+fn term_partial(term: &Term, wrt: &Alpha, side: Side) -> Term {
     let a: Alpha = match side {
-        ApplyFrom::Left => ar_product(wrt, &term.alpha()),
-        ApplyFrom::Right => ar_product(&term.alpha(), wrt),
+        Side::Left => ar_product(wrt, &term.alpha()),
+        Side::Right => ar_product(&term.alpha(), wrt),
     };
 
     let mut t = term.clone();
@@ -72,3 +141,97 @@ fn term_partial(term: &Term, wrt: &Alpha, side: ApplyFrom) -> Term {
 
     return t;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::Form;
+    use crate::prelude::Dmu;
+
+    #[test]
+    fn apply_and_project_gives_the_divergence_of_a_vector_field() {
+        let field = mvec![term!("t", 0), term!("x", 1), term!("y", 2), term!("z", 3)];
+        let div = Dmu().apply_and_project(&field, 0, Side::Left);
+        let mut partials: Vec<String> = div.iter().map(|t| t.xi_str()).collect();
+        partials.sort();
+
+        assert!(div.iter().all(|t| t.alpha() == alpha!()));
+        assert_eq!(partials, vec!["∂0ξt", "∂1ξx", "∂2ξy", "∂3ξz"]);
+        assert_eq!(div, Dmu().left_apply(&field).project_grade(0));
+    }
+
+    #[test]
+    fn into_fn_matches_left_apply() {
+        let d = ArDifferential::new(&[alpha!(0), alpha!(1), alpha!(2), alpha!(3)]);
+        let m = mvec![term!("a", 0 1), -term!(2 3), term!(0 1 2 3)];
+        let expected = d.left_apply(&m);
+
+        assert_eq!(d.into_fn()(m), expected);
+    }
+
+    #[test]
+    fn dual_matches_hand_built_dual_operator() {
+        // ArDifferential::new inverts its arguments so invert the dualised alphas back
+        let dualised: Vec<Alpha> = [alpha!(0), alpha!(1), alpha!(2), alpha!(3)]
+            .iter()
+            .map(|a| a.inverse().dual().inverse())
+            .collect();
+        let m = mvec![term!("a", 0 1), -term!(2 3), term!(0 1 2 3), term!(2)];
+
+        assert_eq!(
+            Dmu().dual().left_apply(&m),
+            ArDifferential::new(&dualised).left_apply(&m)
+        );
+        assert_ne!(Dmu().dual().left_apply(&m), Dmu().left_apply(&m));
+    }
+
+    #[test]
+    fn satisfies_field_equation_detects_non_zero_results() {
+        let field = mvec![
+            term!("x", 2),
+            term!("y", 0 3),
+            -term!("x", 2),
+            -term!("y", 0 3)
+        ];
+        let perturbed = field.clone() + term!("z", 1);
+
+        assert!(satisfies_field_equation(&field, &Dmu()));
+        assert!(!satisfies_field_equation(&perturbed, &Dmu()));
+    }
+
+    #[test]
+    fn commutator_with_vector_field_doubles_anticommuting_parts() {
+        let comm = Dmu().commutator_with(&mvec![term!("x", 1)]);
+        let mut expected = (Dmu().left_apply(&mvec![term!("x", 1)]) * 2).filter_map_terms(|t| {
+            if t.form() == Form::Point {
+                None
+            } else {
+                Some(t.clone())
+            }
+        });
+        expected.simplify();
+
+        assert_eq!(comm.as_terms().len(), 3);
+        assert!(comm.iter().all(|t| t.magnitude() == 2));
+        assert_eq!(comm, expected);
+    }
+
+    #[test]
+    fn leibniz_defect_is_zero_when_a_commutes_with_the_operator() {
+        let a = mvec![Term::new(Some("x"), alpha!())];
+        let b = mvec![term!("y", 2), term!("w", 0 3)];
+
+        assert!(leibniz_defect(&Dmu(), &a, &b).is_zero());
+    }
+
+    #[test]
+    fn leibniz_defect_picks_up_anticommuting_terms() {
+        let a = mvec![term!("x", 1)];
+        let b = mvec![term!("y", 2)];
+        let defect = leibniz_defect(&Dmu(), &a, &b);
+
+        // only ∂1 commutes with a1 so the other three partials of y are doubled
+        assert_eq!(defect.as_terms().len(), 3);
+        assert!(defect.iter().all(|t| t.magnitude() == 2));
+    }
+}
@@ -0,0 +1,247 @@
+//! Treating MultiVectors as vectors in the 16 dimensional coefficient space over the
+//! rationals, with one coordinate per Form in [`ALLOWED_ALPHA_FORMS`]. Xi values are
+//! discarded when moving into this space so only operations that are linear over the
+//! coefficients (conjugations, multiplication by a fixed element etc) are meaningfully
+//! represented here.
+
+use crate::algebra::{full, MultiVector, Ratio};
+
+/// A linear map over the coefficient space: `matrix[i][j]` is the coefficient of the i'th
+/// Form in the image of the j'th basis blade.
+pub type RatioMatrix = [[Ratio; 16]; 16];
+
+/// Extract the matrix of a linear operation by applying it to each of the 16 basis blades
+/// (see [`MultiVector::from_coefficients`]) and recording the resulting coefficients as
+/// the columns of the matrix.
+pub fn linear_matrix_of<F: Fn(&MultiVector) -> MultiVector>(op: F) -> RatioMatrix {
+    let mut matrix = [[Ratio::from(0); 16]; 16];
+
+    for j in 0..16 {
+        let mut blade = [Ratio::from(0); 16];
+        blade[j] = Ratio::from(1);
+
+        let column = op(&MultiVector::from_coefficients(&blade)).coefficients_as_ratios();
+        for (i, c) in column.iter().enumerate() {
+            matrix[i][j] = *c;
+        }
+    }
+
+    matrix
+}
+
+/// A basis for the kernel of a linear operation: the multivectors that it maps to zero.
+pub fn kernel_of<F: Fn(&MultiVector) -> MultiVector>(op: F) -> Vec<MultiVector> {
+    null_space(&linear_matrix_of(op))
+        .iter()
+        .map(MultiVector::from_coefficients)
+        .collect()
+}
+
+/// The multivectors fixed by left multiplication by the given element: a basis for the
+/// eigenspace with eigenvalue 1, found as the null space of `M - I` where M is the matrix
+/// of left multiplication.
+pub fn fixed_points_of_left_multiplication(by: &MultiVector) -> Vec<MultiVector> {
+    let mut matrix = linear_matrix_of(|m| full(by, m));
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[i] = row[i] - Ratio::from(1);
+    }
+
+    null_space(&matrix)
+        .iter()
+        .map(MultiVector::from_coefficients)
+        .collect()
+}
+
+/// Solve the linear system `matrix . x = rhs` exactly using Gaussian elimination, where
+/// `matrix` is given as its 16 rows. Returns None if the system is singular (or if matrix
+/// does not have 16 rows).
+pub fn solve_rational(matrix: &[[Ratio; 16]], rhs: &[Ratio; 16]) -> Option<[Ratio; 16]> {
+    if matrix.len() != 16 {
+        return None;
+    }
+
+    let mut rows: Vec<([Ratio; 16], Ratio)> =
+        matrix.iter().copied().zip(rhs.iter().copied()).collect();
+
+    for col in 0..16 {
+        let pivot = (col..16).find(|&i| rows[i].0[col].magnitude() != 0)?;
+        rows.swap(col, pivot);
+
+        let p = rows[col].0[col];
+        rows[col].0.iter_mut().for_each(|x| *x = *x / p);
+        rows[col].1 = rows[col].1 / p;
+
+        let (pivot_row, pivot_rhs) = rows[col];
+        for (i, (row, b)) in rows.iter_mut().enumerate() {
+            let factor = row[col];
+            if i != col && factor.magnitude() != 0 {
+                row.iter_mut()
+                    .zip(pivot_row.iter())
+                    .for_each(|(x, y)| *x = *x - factor * *y);
+                *b = *b - factor * pivot_rhs;
+            }
+        }
+    }
+
+    let mut solution = [Ratio::from(0); 16];
+    for (x, (_, b)) in solution.iter_mut().zip(rows.iter()) {
+        *x = *b;
+    }
+
+    Some(solution)
+}
+
+// Reduce the matrix to reduced row echelon form, returning the reduced rows along with
+// the column of the pivot in each non-zero row.
+fn row_reduce(matrix: &RatioMatrix) -> (RatioMatrix, Vec<usize>) {
+    let mut rows = *matrix;
+    let mut pivot_cols = vec![];
+
+    for col in 0..16 {
+        let r = pivot_cols.len();
+        let pivot = match (r..16).find(|&i| rows[i][col].magnitude() != 0) {
+            Some(p) => p,
+            None => continue,
+        };
+        rows.swap(r, pivot);
+
+        let p = rows[r][col];
+        rows[r].iter_mut().for_each(|x| *x = *x / p);
+
+        for i in 0..16 {
+            let factor = rows[i][col];
+            if i != r && factor.magnitude() != 0 {
+                let pivot_row = rows[r];
+                rows[i]
+                    .iter_mut()
+                    .zip(pivot_row.iter())
+                    .for_each(|(x, y)| *x = *x - factor * *y);
+            }
+        }
+        pivot_cols.push(col);
+    }
+
+    (rows, pivot_cols)
+}
+
+// A basis for the null space of the matrix: one vector for each free (non-pivot) column.
+fn null_space(matrix: &RatioMatrix) -> Vec<[Ratio; 16]> {
+    let (rows, pivot_cols) = row_reduce(matrix);
+
+    (0..16)
+        .filter(|c| !pivot_cols.contains(c))
+        .map(|free| {
+            let mut v = [Ratio::from(0); 16];
+            v[free] = Ratio::from(1);
+            for (r, &c) in pivot_cols.iter().enumerate() {
+                v[c] = -rows[r][free];
+            }
+            v
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::{Form, ALLOWED_ALPHA_FORMS, AR};
+
+    #[test]
+    fn matrix_of_diamond_is_diagonal() {
+        let matrix = linear_matrix_of(|m| m.diamond());
+
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, c) in row.iter().enumerate() {
+                let expected = match (i, j) {
+                    (0, 0) => 1,
+                    _ if i == j => -1,
+                    _ => 0,
+                };
+                assert_eq!(*c, Ratio::from(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn kernel_of_removing_the_bivector_part_is_the_bivectors() {
+        let kernel = kernel_of(|m| m.clone() - m.project_grade(2));
+        let mut forms: Vec<Form> = kernel
+            .iter()
+            .flat_map(|m| m.iter().map(|t| t.form()))
+            .collect();
+        forms.sort();
+
+        assert_eq!(kernel.len(), 6);
+        assert!(kernel.iter().all(|m| m.as_terms().len() == 1));
+        assert_eq!(
+            forms,
+            ALLOWED_ALPHA_FORMS
+                .iter()
+                .filter(|f| f.grade() == 2)
+                .copied()
+                .collect::<Vec<Form>>()
+        );
+    }
+
+    #[test]
+    fn everything_is_fixed_by_ap() {
+        let fixed = fixed_points_of_left_multiplication(&MultiVector::identity());
+
+        assert_eq!(fixed.len(), 16);
+    }
+
+    #[test]
+    fn idempotents_fix_their_image() {
+        let p = mvec![term!(), term!(0)] / 2;
+        let fixed = fixed_points_of_left_multiplication(&p);
+
+        assert_eq!(fixed.len(), 8);
+        for v in fixed.iter() {
+            let image: MultiVector = full(&p, v);
+            assert_eq!(image.coefficients_as_ratios(), v.coefficients_as_ratios());
+        }
+    }
+
+    fn apply(matrix: &RatioMatrix, x: &[Ratio; 16]) -> [Ratio; 16] {
+        matrix.map(|row| {
+            row.iter()
+                .zip(x.iter())
+                .fold(Ratio::from(0), |acc, (a, b)| acc + *a * *b)
+        })
+    }
+
+    fn coupled_matrix() -> RatioMatrix {
+        let mut matrix = [[Ratio::from(0); 16]; 16];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = Ratio::from(1);
+        }
+        matrix[0][1] = Ratio::from(2);
+        matrix[1][0] = Ratio::from(3);
+        matrix[1][1] = Ratio::from(-1);
+        matrix[2][0] = Ratio::new(1, 2);
+        matrix[7][3] = Ratio::new(-2, 3);
+        matrix
+    }
+
+    #[test]
+    fn solve_rational_recovers_known_solution() {
+        let matrix = coupled_matrix();
+        let mut expected = [Ratio::from(0); 16];
+        for (i, x) in expected.iter_mut().enumerate() {
+            *x = Ratio::new(i as isize % 5 - 2, 3);
+        }
+
+        let solution = solve_rational(&matrix, &apply(&matrix, &expected));
+        assert_eq!(solution, Some(expected));
+    }
+
+    #[test]
+    fn solve_rational_detects_singular_systems() {
+        let mut matrix = coupled_matrix();
+        matrix[5] = matrix[2];
+        let rhs = [Ratio::from(1); 16];
+
+        assert_eq!(solve_rational(&matrix, &rhs), None);
+        assert_eq!(solve_rational(&matrix[..4], &rhs), None);
+    }
+}
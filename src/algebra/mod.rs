@@ -2,7 +2,8 @@
 //! within the framework of Absolute Relativity.
 
 pub mod differentials;
+pub mod linalg;
 pub mod operations;
 pub mod types;
 
-pub use self::{differentials::*, operations::*, types::*};
+pub use self::{differentials::*, linalg::*, operations::*, types::*};
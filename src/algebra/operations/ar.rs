@@ -1,7 +1,7 @@
 use std::mem;
 
 use super::ar_product;
-use crate::algebra::types::{Alpha, Form, Index, Sign, Term};
+use crate::algebra::types::{Alpha, Form, Index, MultiVector, Sign, Term};
 
 /// Types that implement AR are able to be consumed by any of the library operations
 /// provided by arthroprod. The return of these library functions is typically something
@@ -48,6 +48,14 @@ pub trait AR {
         Self::Output::from_terms(alphas.iter().map(|a| Term::new(None, a.clone())).collect())
     }
 
+    /// The number of terms remaining once like terms have been combined. self is
+    /// left untouched.
+    fn term_count(&self) -> usize {
+        let mut m = MultiVector::from_terms(self.as_terms());
+        m.simplify();
+        m.as_terms().len()
+    }
+
     /// Check to see if self is entirely composed of scalar elements within
     /// the algebra (i.e. Point: nothing of a higher grade)
     fn is_scalar(&self) -> bool {
@@ -63,6 +71,7 @@ pub trait AR {
     /// or the Quadrivector, the net sign following pops is unchanged.
     /// For Bivectors and Trivectors the sign is reversed:
     ///
+    /// p -> p       (no indices: no sign change)
     /// a -> a       (no pops: no sign change)
     /// ab -> ba     (1 pop:   sign change)
     /// abc -> cba   (3 pops:  sign change)
@@ -85,8 +94,8 @@ pub trait AR {
             self.as_terms()
                 .iter()
                 .map(|t| match t.alpha().form() {
-                    Form::Vector(_) | Form::Quadrivector(_, _, _, _) => t.clone(),
-                    _ => -t.clone(),
+                    Form::Point | Form::Vector(_) | Form::Quadrivector(_, _, _, _) => t.clone(),
+                    _ => -t,
                 })
                 .collect(),
         )
@@ -105,6 +114,18 @@ pub trait AR {
         )
     }
 
+    /// Grade-projection by grade number rather than by example [`Form`]: filter terms,
+    /// leaving only those whose Form has the given grade (0 for ap through 4 for a0123).
+    fn project_grade(&self, grade: usize) -> Self::Output {
+        Self::Output::from_terms(
+            self.as_terms()
+                .iter()
+                .filter(|t| t.form().grade() == grade)
+                .cloned()
+                .collect(),
+        )
+    }
+
     /// Compute the Hermitian conjugate (dagger) of the argument. This has the
     /// effect of negating all terms whos alphas square to -ap.
     ///
@@ -116,7 +137,7 @@ pub trait AR {
             self.as_terms()
                 .iter()
                 .map(|t| match ar_product(&t.alpha(), &t.alpha()).sign() {
-                    Sign::Neg => -t.clone(),
+                    Sign::Neg => -t,
                     Sign::Pos => t.clone(),
                 })
                 .collect(),
@@ -136,7 +157,7 @@ pub trait AR {
                 .iter()
                 .map(|t| match t.form() {
                     Form::Point => t.clone(),
-                    _ => -t.clone(),
+                    _ => -t,
                 })
                 .collect(),
         )
@@ -150,7 +171,7 @@ pub trait AR {
                 .iter()
                 .map(|t| match t.form() {
                     Form::Bivector(_, _) => t.clone(),
-                    _ => -t.clone(),
+                    _ => -t,
                 })
                 .collect(),
         )
@@ -161,10 +182,7 @@ pub trait AR {
     /// It is the inverse of an element through a0123 as opposed to ap, meaning that
     /// the product of an element with its dual is always a0123.
     fn dual(&self) -> Self::Output {
-        let indices = [0, 1, 2, 3]
-            .iter()
-            .map(|n| Index::try_from_u8(*n).unwrap())
-            .collect();
+        let indices = Index::all().to_vec();
         let q = Term::new(None, Alpha::try_from_indices(Sign::Neg, &indices).unwrap());
 
         Self::Output::from_terms(
@@ -206,7 +224,7 @@ impl AR for Vec<Alpha> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::algebra::{ar_product, Alpha, MultiVector, Term, ALLOWED_ALPHA_FORMS};
+    use crate::algebra::{ar_product, Alpha, Term, ALLOWED_ALPHA_FORMS};
 
     #[test]
     fn hermitian_conjugation_is_correct_for_alphas() {
@@ -246,4 +264,51 @@ mod tests {
         let conjugate = MultiVector::from_terms(terms).hermitian();
         assert_eq!(conjugate, MultiVector::from_terms(negated));
     }
+
+    #[test]
+    fn reversion_leaves_ap_unchanged() {
+        let ap = Alpha::new(Sign::Pos, Form::Point).unwrap();
+
+        assert_eq!(ap.reversed(), ap);
+        assert_eq!(term!().reversed(), term!());
+        assert_eq!(
+            mvec![term!(), term!(1 2)].reversed(),
+            mvec![term!(), -term!(1 2)]
+        );
+    }
+
+    #[test]
+    fn term_count_counts_simplified_terms() {
+        let m = mvec![
+            term!("a", 1),
+            -term!("a", 1),
+            term!("b", 1),
+            term!("b", 1),
+            term!(2 3)
+        ];
+
+        assert_eq!(m.term_count(), 2);
+        assert_eq!(m.as_terms().len(), 5);
+        assert_eq!(term!(0).term_count(), 1);
+    }
+
+    #[test]
+    fn project_grade_matches_project() {
+        let m = mvec![
+            term!(),
+            term!(0),
+            term!(2 3),
+            term!(0 1),
+            term!(0 2 3),
+            term!(0 1 2 3)
+        ];
+
+        assert_eq!(m.project_grade(0), m.project(&Form::Point));
+        assert_eq!(m.project_grade(1), m.project(&Form::Vector(Index::One)));
+        assert_eq!(
+            m.project_grade(2),
+            m.project(&Form::Bivector(Index::One, Index::Two))
+        );
+        assert_eq!(m.project_grade(2).as_terms().len(), 2);
+    }
 }
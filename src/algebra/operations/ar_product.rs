@@ -36,21 +36,58 @@
 //! repeat the process until we are done.
 //!
 
-use crate::algebra::{Alpha, Form, Index, Sign};
+use std::error;
+use std::fmt;
+
+use crate::algebra::{Alpha, Form, Index, Sign, ALLOWED_ALPHA_FORMS};
+
+/// Errors that can occur when forming the product of two [`Alpha`] values
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ArError {
+    /// A Form that is not found in ALLOWED_ALPHA_FORMS, given by its indices
+    InvalidForm(Vec<Index>),
+}
+
+impl fmt::Display for ArError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArError::InvalidForm(ixs) => {
+                let ixs: Vec<String> = ixs.iter().map(|i| i.to_string()).collect();
+                write!(f, "Invalid Alpha form: a{}", ixs.join(""))
+            }
+        }
+    }
+}
+
+impl error::Error for ArError {}
 
 /// Compute the full product of i and j under the +--- metric and form ordering
 /// conventions given in ALLOWED_ALPHA_formS.
 /// This function will panic if invalid forms are somehow provided in order to
-/// prevent malformed calculations from running.
+/// prevent malformed calculations from running: see [`try_ar_product`] for a
+/// fallible version.
 pub fn ar_product(i: &Alpha, j: &Alpha) -> Alpha {
+    try_ar_product(i, j).unwrap()
+}
+
+/// Compute the full product of i and j as with [`ar_product`], returning an
+/// [`ArError::InvalidForm`] rather than panicking if either Alpha has a Form that is not
+/// found in ALLOWED_ALPHA_FORMS (i.e. one that has been deserialized without validation).
+pub fn try_ar_product(i: &Alpha, j: &Alpha) -> Result<Alpha, ArError> {
     let mut sign = i.sign().combine(&j.sign());
     let i_form = i.form();
     let j_form = j.form();
 
+    for form in [i_form, j_form].iter() {
+        if !ALLOWED_ALPHA_FORMS.contains(form) {
+            return Err(ArError::InvalidForm(form.as_vec()));
+        }
+    }
+
     // Multiplication by ap is idempotent on the form but does affect sign
     match (i.form(), j.form()) {
-        (Form::Point, _) => return Alpha::new(sign, j_form).unwrap(),
-        (_, Form::Point) => return Alpha::new(sign, i_form).unwrap(),
+        (Form::Point, _) => return Ok(Alpha::new(sign, j_form).unwrap()),
+        (_, Form::Point) => return Ok(Alpha::new(sign, i_form).unwrap()),
         _ => (),
     };
 
@@ -59,16 +96,15 @@ pub fn ar_product(i: &Alpha, j: &Alpha) -> Alpha {
 
     // For ap and vectors we don't have an ordering to worry about
     match axes.len() {
-        0 => return Alpha::new(sign, Form::Point).unwrap(),
-        1 => return Alpha::new(sign, Form::Vector(axes[0])).unwrap(),
+        0 => return Ok(Alpha::new(sign, Form::Point).unwrap()),
+        1 => return Ok(Alpha::new(sign, Form::Vector(axes[0])).unwrap()),
         _ => (),
     };
 
     let (ordering_sign, target) = pop_to_correct_ordering(&axes);
     sign = sign.combine(&ordering_sign);
 
-    let comp = Form::try_from_indices(&target).unwrap();
-    return Alpha::new(sign, comp).unwrap();
+    Alpha::try_from_indices(sign, &target).map_err(|_| ArError::InvalidForm(target))
 }
 
 // NOTE: This is where we are hard coding the +--- metric along with assuming
@@ -252,4 +288,20 @@ mod tests {
             assert_eq!(ar_product(&alpha, &alpha.inverse()), ap);
         }
     }
+
+    #[test]
+    fn try_ar_product_errors_on_invalid_forms() {
+        let ap = Alpha::new(Sign::Pos, Form::Point).unwrap();
+        let a2 = Alpha::new(Sign::Pos, Form::Vector(Index::Two)).unwrap();
+        let bad: Alpha =
+            serde_json::from_str(r#"{"sign":"Pos","form":{"Bivector":["One","One"]}}"#).unwrap();
+
+        let invalid = ArError::InvalidForm(vec![Index::One, Index::One]);
+
+        assert_eq!(try_ar_product(&bad, &ap), Err(invalid.clone()));
+        assert_eq!(try_ar_product(&ap, &bad), Err(invalid.clone()));
+        assert_eq!(try_ar_product(&bad, &a2), Err(invalid.clone()));
+        assert_eq!(try_ar_product(&a2, &a2), Ok(-ap));
+        assert_eq!(invalid.to_string(), "Invalid Alpha form: a11");
+    }
 }
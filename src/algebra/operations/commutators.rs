@@ -0,0 +1,62 @@
+//! Commutators and the Lie algebraic structure that they give to the algebra.
+
+use crate::algebra::{full, MultiVector, AR};
+
+/// The commutator `[A, B] = A ^ B - B ^ A`, simplified.
+pub fn commutator<L: AR, R: AR>(left: &L, right: &R) -> MultiVector {
+    let lr: MultiVector = full(left, right);
+    let rl: MultiVector = full(right, left);
+    let mut res = lr - rl;
+    res.simplify();
+
+    res
+}
+
+/// The symmetric and antisymmetric parts of the full product, `((AB + BA) / 2, (AB - BA) / 2)`,
+/// simplified. AB and BA are each only computed once.
+pub fn symmetric_antisymmetric_split<L: AR, R: AR>(
+    left: &L,
+    right: &R,
+) -> (MultiVector, MultiVector) {
+    let lr: MultiVector = full(left, right);
+    let rl: MultiVector = full(right, left);
+    let mut sym = (lr.clone() + rl.clone()) / 2;
+    let mut anti = (lr - rl) / 2;
+    sym.simplify();
+    anti.simplify();
+
+    (sym, anti)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commuting_elements_have_zero_commutator() {
+        assert_eq!(commutator(&alpha!(0), &alpha!(2 3)), MultiVector::new());
+        assert_eq!(commutator(&term!(1), &term!(1)), MultiVector::new());
+    }
+
+    #[test]
+    fn anticommuting_elements_have_doubled_commutator() {
+        let lr: MultiVector = full(&term!(1), &term!(2));
+
+        assert_eq!(commutator(&term!(1), &term!(2)), lr * 2);
+    }
+
+    #[test]
+    fn symmetric_and_antisymmetric_parts_sum_to_the_full_product() {
+        let left = mvec![term!("a", 1), term!("b", 0 2), term!("c", 0)];
+        let right = mvec![term!("d", 2), term!("e", 0 1 2 3)];
+        let (sym, anti) = symmetric_antisymmetric_split(&left, &right);
+
+        let mut expected: MultiVector = full(&left, &right);
+        expected.simplify();
+        let mut sum = sym + anti.clone();
+        sum.simplify();
+
+        assert_eq!(sum, expected);
+        assert_eq!(anti * 2, commutator(&left, &right));
+    }
+}
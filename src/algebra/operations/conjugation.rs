@@ -0,0 +1,71 @@
+//! The conjugations of the algebra as data, allowing them to be selected at runtime and
+//! their action on the basis elements to be inspected.
+
+use crate::algebra::{Alpha, Form, Sign, ALLOWED_ALPHA_FORMS, AR};
+
+/// The sign-flipping conjugations provided by the [`AR`] trait. Each of these maps every
+/// basis element to plus or minus itself.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Conjugation {
+    /// Reverse the order of the indices in each term
+    Reverse,
+    /// The Hermitian conjugate
+    Dagger,
+    /// The diamond conjugate
+    Diamond,
+    /// The double dagger conjugate
+    DoubleDagger,
+}
+
+impl Conjugation {
+    /// Apply this conjugation to any AR value
+    pub fn apply<T: AR>(&self, arg: &T) -> T::Output {
+        match self {
+            Conjugation::Reverse => arg.reversed(),
+            Conjugation::Dagger => arg.hermitian(),
+            Conjugation::Diamond => arg.diamond(),
+            Conjugation::DoubleDagger => arg.double_dagger(),
+        }
+    }
+
+    /// The basis Forms that are left unchanged by this conjugation, in the order given
+    /// by [`ALLOWED_ALPHA_FORMS`]
+    pub fn fixed_forms(&self) -> Vec<Form> {
+        self.forms_with_sign(Sign::Pos)
+    }
+
+    /// The basis Forms that are negated by this conjugation, in the order given by
+    /// [`ALLOWED_ALPHA_FORMS`]
+    pub fn negated_forms(&self) -> Vec<Form> {
+        self.forms_with_sign(Sign::Neg)
+    }
+
+    fn forms_with_sign(&self, sign: Sign) -> Vec<Form> {
+        ALLOWED_ALPHA_FORMS
+            .iter()
+            .filter(|f| self.apply(&Alpha::new(Sign::Pos, **f).unwrap()).sign() == sign)
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn diamond_fixes_only_point() {
+        assert_eq!(Conjugation::Diamond.fixed_forms(), vec![Form::Point]);
+        assert_eq!(Conjugation::Diamond.negated_forms().len(), 15);
+    }
+
+    #[test_case(Conjugation::Reverse, 6)]
+    #[test_case(Conjugation::Dagger, 6)]
+    #[test_case(Conjugation::Diamond, 1)]
+    #[test_case(Conjugation::DoubleDagger, 6)]
+    fn fixed_and_negated_forms_partition_the_basis(c: Conjugation, n_fixed: usize) {
+        assert_eq!(c.fixed_forms().len(), n_fixed);
+        assert_eq!(c.fixed_forms().len() + c.negated_forms().len(), 16);
+    }
+}
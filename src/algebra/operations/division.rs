@@ -6,7 +6,12 @@ use crate::algebra::{full, MultiVector, Term, AR};
 
 /// Divide left into right. When left and right are both terms or alphas, this is a relatively
 /// simple inversion of left and then forming the full product. For MultiVectors this requires
-/// a full general inverse using the Van Der Mark
+/// a full general inverse using the Van Der Mark, dividing through by the magnitude of
+/// [`van_der_mark_scalar`].
+///
+/// # Panics
+///
+/// Panics if the Van Der Mark scalar of a multi-term left does not simplify to a single term.
 pub fn div<L: AR, R: AR, T: AR>(left: &L, right: &R) -> T {
     let lterms = left.as_terms();
     let rterms = right.as_terms();
@@ -20,6 +25,25 @@ pub fn div<L: AR, R: AR, T: AR>(left: &L, right: &R) -> T {
     T::from_terms(terms)
 }
 
+/// The Van Der Mark scalar `phi ^ diamond(phi)` where `phi = M ^ hermitian(M)`, simplified.
+/// This is always a pure ap scalar but it is only guaranteed to be a single term when m
+/// contains a single term: otherwise it is in general a sum of ap terms over distinct
+/// products of Xi values.
+pub fn van_der_mark_scalar(m: &MultiVector) -> MultiVector {
+    van_der_mark_parts(m).1
+}
+
+// The pair (diamond(phi), phi ^ diamond(phi)) for phi = m ^ hermitian(m), both simplified
+fn van_der_mark_parts<M: AR>(m: &M) -> (MultiVector, MultiVector) {
+    let mut phi: MultiVector = full(m, &m.hermitian());
+    phi.simplify();
+    let diamond_phi = phi.diamond();
+    let mut scalar: MultiVector = full(&phi, &diamond_phi);
+    scalar.simplify();
+
+    (diamond_phi, scalar)
+}
+
 // dividing left into right (left \ right)
 fn div_single_terms(left: &Term, right: &Term) -> Vec<Term> {
     vec![left.form_product_with(&right.inverse())]
@@ -27,15 +51,54 @@ fn div_single_terms(left: &Term, right: &Term) -> Vec<Term> {
 
 // dividing left into right (left \ right)
 fn apply_van_der_mark<L: AR, R: AR>(left: &L, right: &R) -> Vec<Term> {
-    let l_dagger = left.hermitian();
-    let l_phi: MultiVector = full(left, &l_dagger);
-    let l_diamond_phi = l_phi.diamond();
-
-    // guaranteed to be a single ap term when computing phi ^ diamond(phi)
-    let t: Term = full(&l_phi, &l_diamond_phi);
-    let divisor = t.magnitude();
-    let inverse: MultiVector = full(&l_dagger, &l_diamond_phi);
+    let (l_diamond_phi, scalar) = van_der_mark_parts(left);
+    let divisor = match scalar.as_terms().as_slice() {
+        [t] => t.magnitude(),
+        _ => panic!("Van Der Mark scalar is not a single term: {}", scalar),
+    };
+    let inverse: MultiVector = full(&left.hermitian(), &l_diamond_phi);
     let product: MultiVector = full(&inverse, right);
 
     (product / divisor).as_terms()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{A, B, E, T};
+
+    #[test]
+    fn van_der_mark_scalar_is_scalar_for_time_and_space_like_combinations() {
+        let time_like = [term!(), term!(0), term!(1 2 3), term!(0 1 2 3)];
+        let space_like = [B(), T(), A(), E()];
+
+        for t in time_like.iter() {
+            for s in space_like.iter() {
+                let scalar = van_der_mark_scalar(&mvec![s, t]);
+
+                assert!(!scalar.as_terms().is_empty());
+                assert!(scalar.is_scalar());
+            }
+        }
+    }
+
+    #[test]
+    fn dividing_a_multivector_into_itself_gives_the_identity() {
+        let m = mvec![
+            Term::from_xis_and_alpha(vec![], alpha!(0)) * 2usize,
+            Term::from_xis_and_alpha(vec![], alpha!(2 3))
+        ];
+        let mut res: MultiVector = div(&m, &m);
+        res.simplify();
+
+        assert_eq!(res, MultiVector::identity());
+    }
+
+    #[test]
+    fn van_der_mark_scalar_of_a_single_term_is_a_single_term() {
+        let scalar = van_der_mark_scalar(&mvec![term!("x", 0 2 3)]);
+
+        assert_eq!(scalar.as_terms().len(), 1);
+        assert!(scalar.is_scalar());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::algebra::{Term, AR};
+use std::collections::HashSet;
+
+use crate::algebra::{ar_product, signed_sum, Form, Magnitude, MultiVector, Sign, Term, AR};
 
 /// The full product between two elements within AR is defined as an extension of the traditional
 /// Clifford product from a Clifford Algebera: we form the Cartesian product of the terms in left
@@ -17,3 +19,220 @@ pub fn full<L: AR, R: AR, T: AR>(left: &L, right: &R) -> T {
             .collect(),
     )
 }
+
+/// The full product of left and right as a MultiVector with every individual term product
+/// kept: like terms are not combined so cancellations can be traced by hand. This is
+/// [`full`] with the output type fixed.
+pub fn full_raw<L: AR, R: AR>(left: &L, right: &R) -> MultiVector {
+    full(left, right)
+}
+
+/// The pairs of terms from left and right whose full product has the target Form. Only the
+/// Alphas of each pair are multiplied in order to check this.
+pub fn contributing_pairs(
+    left: &MultiVector,
+    right: &MultiVector,
+    target: &Form,
+) -> Vec<(Term, Term)> {
+    left.iter()
+        .flat_map(|l| {
+            right
+                .iter()
+                .filter(move |r| &ar_product(&l.alpha(), &r.alpha()).form() == target)
+                .map(move |r| (l.clone(), r.clone()))
+        })
+        .collect()
+}
+
+/// The part of the full product of left and right with one of the given Forms. The Alphas
+/// of each pair of terms are multiplied once and only pairs landing on one of the requested
+/// Forms have their full product (including Xi values) computed. Repeated Forms are only
+/// counted once. As with [`full`], like terms are not combined.
+pub fn full_onto(left: &MultiVector, right: &MultiVector, forms: &[Form]) -> MultiVector {
+    let forms: &HashSet<Form> = &forms.iter().cloned().collect();
+
+    left.iter()
+        .flat_map(|l| {
+            right.iter().filter_map(move |r| {
+                let alpha = ar_product(&l.alpha(), &r.alpha());
+                if forms.contains(&alpha.form()) {
+                    Some(l.form_product_with_alpha(r, alpha))
+                } else {
+                    None
+                }
+            })
+        })
+        .collect()
+}
+
+/// The combined coefficient of the a0123 part of the full product of left and right, with
+/// Xi values discarded. Only the products of Alphas are formed and only pairs landing on
+/// a0123 contribute, so this is far cheaper than projecting the result of [`full`].
+pub fn full_pseudoscalar_part<L: AR, R: AR>(left: &L, right: &R) -> (Sign, Magnitude) {
+    let rterms = right.as_terms();
+
+    signed_sum(left.as_terms().iter().flat_map(|l| {
+        rterms.iter().filter_map(move |r| {
+            let alpha = ar_product(&l.alpha(), &r.alpha());
+            match alpha.form() {
+                Form::Quadrivector(_, _, _, _) => {
+                    Some((alpha.sign(), l.magnitude() * r.magnitude()))
+                }
+                _ => None,
+            }
+        })
+    }))
+}
+
+/// The full product of two MultiVectors, simplified, using scratch as the buffer for the
+/// intermediate term products rather than allocating a new one on each call. scratch is
+/// cleared before use and drained (keeping its capacity) so it can be shared across
+/// repeated calls in a hot loop.
+pub fn full_reuse(left: &MultiVector, right: &MultiVector, scratch: &mut Vec<Term>) -> MultiVector {
+    scratch.clear();
+    left.iter()
+        .for_each(|l| scratch.extend(right.iter().map(|r| l.form_product_with(r))));
+
+    let mut res: MultiVector = scratch.drain(..).collect();
+    res.simplify();
+    res
+}
+
+/// Form the full product of a sequence of MultiVectors (left associated) and then simplify
+/// the result. The product of an empty slice is [`MultiVector::identity`].
+pub fn product_all(factors: &[MultiVector]) -> MultiVector {
+    let mut res = match factors.split_first() {
+        None => return MultiVector::identity(),
+        Some((first, rest)) => rest.iter().fold(first.clone(), |acc, m| full(&acc, m)),
+    };
+
+    res.simplify();
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::Index;
+
+    #[test]
+    fn product_all_matches_nested_full() {
+        let a = mvec![term!("a", 0), term!("b", 2 3)];
+        let b = mvec![term!("c", 1), -term!("d", 0 1)];
+        let c = mvec![term!("e", 0 1 2 3), term!("f", 0 2)];
+
+        let mut expected: MultiVector = full(&full::<_, _, MultiVector>(&a, &b), &c);
+        expected.simplify();
+
+        assert_eq!(product_all(&[a, b, c]), expected);
+    }
+
+    #[test]
+    fn full_reuse_matches_full_across_calls() {
+        let a = mvec![term!("a", 0), term!("b", 2 3), -term!("c", 1)];
+        let b = mvec![term!("c", 1), -term!("d", 0 1)];
+        let mut scratch = vec![];
+
+        let mut expected: MultiVector = full(&a, &b);
+        expected.simplify();
+        let mut expected_squared: MultiVector = full(&a, &a);
+        expected_squared.simplify();
+
+        assert_eq!(full_reuse(&a, &b, &mut scratch), expected);
+        let capacity = scratch.capacity();
+        assert_eq!(full_reuse(&a, &a, &mut scratch), expected_squared);
+        assert_eq!(full_reuse(&a, &b, &mut scratch), expected);
+        assert!(scratch.is_empty());
+        assert!(scratch.capacity() >= capacity);
+    }
+
+    #[test]
+    fn full_pseudoscalar_part_matches_projection_of_full() {
+        let a = mvec![
+            term!("a", 0),
+            term!("b", 2 3),
+            -term!("c", 1) * 2usize,
+            term!(0 1 2 3)
+        ];
+        let b = mvec![term!("c", 1 2 3), -term!("d", 0 1), term!(2 3), term!()];
+        let projected: MultiVector = full::<_, _, MultiVector>(&a, &b).project_grade(4);
+        let expected = signed_sum(projected.iter().map(|t| (t.sign(), t.magnitude())));
+
+        assert_eq!(full_pseudoscalar_part(&a, &b), expected);
+        assert_eq!(
+            full_pseudoscalar_part(&a, &mvec![term!(2)]),
+            (Sign::Pos, 0.into())
+        );
+    }
+
+    #[test]
+    fn full_raw_keeps_every_term_product() {
+        let a = mvec![term!("a", 0), term!("b", 2 3), -term!("c", 0)];
+        let b = mvec![term!("c", 1), -term!("d", 0 1)];
+        let raw = full_raw(&a, &b);
+
+        let mut simplified = raw.clone();
+        simplified.simplify();
+        let mut expected: MultiVector = full(&a, &b);
+        expected.simplify();
+
+        assert_eq!(raw.as_terms().len(), 6);
+        assert_eq!(simplified, expected);
+    }
+
+    #[test]
+    fn contributing_pairs_all_land_on_the_target() {
+        let a = mvec![
+            term!("a", 0),
+            term!("b", 2 3),
+            -term!("c", 1),
+            term!(0 1 2 3)
+        ];
+        let b = mvec![term!("c", 1 2 3), -term!("d", 0 1), term!(2 3), term!()];
+        let target = Form::Bivector(Index::Two, Index::Three);
+        let pairs = contributing_pairs(&a, &b, &target);
+
+        assert_eq!(pairs.len(), 3);
+        for (l, r) in pairs.iter() {
+            assert_eq!(l.form_product_with(r).form(), target);
+        }
+    }
+
+    #[test]
+    fn full_onto_point_matches_grade_zero_projection() {
+        let a = mvec![term!("a", 0), term!("b", 2 3), -term!("c", 1), term!()];
+        let b = mvec![term!("c", 1), -term!("d", 0), term!(2 3), term!(0 1)];
+        let projected = full_raw(&a, &b).project_grade(0);
+
+        assert_eq!(full_onto(&a, &b, &[Form::Point]), projected);
+        assert_eq!(
+            full_onto(&a, &b, &[Form::Point, Form::Vector(Index::One)]),
+            projected
+                + full_raw(&a, &b).filter_map_terms(|t| match t.form() {
+                    Form::Vector(Index::One) => Some(t.clone()),
+                    _ => None,
+                })
+        );
+    }
+
+    #[test]
+    fn full_onto_ignores_repeated_forms() {
+        let a = mvec![term!("a", 0), term!("b", 2 3), -term!("c", 1), term!()];
+        let b = mvec![term!("c", 1), -term!("d", 0), term!(2 3), term!(0 1)];
+
+        assert_eq!(
+            full_onto(&a, &b, &[Form::Point, Form::Point]),
+            full_onto(&a, &b, &[Form::Point])
+        );
+        assert_eq!(
+            full_onto(&a, &b, &[Form::Point, Form::Point]),
+            full_raw(&a, &b).project_grade(0)
+        );
+    }
+
+    #[test]
+    fn product_all_of_nothing_is_the_identity() {
+        assert_eq!(product_all(&[]), MultiVector::identity());
+        assert_eq!(MultiVector::identity().to_string(), "{\n  ap    ( +1 )\n}");
+    }
+}
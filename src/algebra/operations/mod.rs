@@ -6,7 +6,31 @@
 
 mod ar;
 mod ar_product;
+mod commutators;
+mod conjugation;
 mod division;
 mod full_product;
+mod pipeline;
+mod sandwich;
+mod structure;
 
-pub use self::{ar::AR, ar_product::ar_product, division::div, full_product::full};
+#[cfg(feature = "parallel")]
+pub use self::pipeline::apply_to_all_parallel;
+pub use self::{
+    ar::AR,
+    ar_product::{ar_product, try_ar_product, ArError},
+    commutators::{commutator, symmetric_antisymmetric_split},
+    conjugation::Conjugation,
+    division::{div, van_der_mark_scalar},
+    full_product::{
+        contributing_pairs, full, full_onto, full_pseudoscalar_part, full_raw, full_reuse,
+        product_all,
+    },
+    pipeline::{apply_to_all, replay, CalculationLog, Operation},
+    sandwich::{conjugate_by, reflect},
+    structure::{
+        anticommutation_matrix, compare_cayley_to, find_roots_of_unity, find_zero_divisor_pair,
+        generated_subalgebra, is_closed_under_product, is_orthonormal_frame, structure_constants,
+        verify_even_closure, verify_jacobi, CayleyMismatch, CayleyTable,
+    },
+};
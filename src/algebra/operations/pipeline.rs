@@ -0,0 +1,254 @@
+//! Data driven pipelines of operations on MultiVectors.
+//!
+//! Chains of conjugations, products and projections are the bread and butter of working
+//! within AR. Describing them as a slice of [`Operation`]s rather than nested function
+//! calls allows derivations to be written (and tested) declaratively.
+
+use crate::algebra::{full, MultiVector, Ratio, AR, SERIALIZATION_VERSION};
+
+/// A single step in a pipeline run by [`MultiVector::apply_pipeline`].
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum Operation {
+    /// Form the full product `current ^ operand`
+    Full(MultiVector),
+    /// Form the full product `operand ^ current`
+    FullLeft(MultiVector),
+    /// Form the full product of the current value with the result of running the
+    /// nested pipeline over it: `current ^ pipeline(current)`
+    FullWith(Vec<Operation>),
+    /// The Hermitian conjugate
+    Dagger,
+    /// Reverse the order of the indices in each term
+    Reverse,
+    /// The diamond conjugate
+    Diamond,
+    /// The double dagger conjugate
+    DoubleDagger,
+    /// The dual (product with -a0123)
+    Dual,
+    /// Keep only terms of the given grade
+    Project(usize),
+    /// Multiply through by a signed rational
+    Scale(Ratio),
+    /// Combine like terms
+    Simplify,
+}
+
+impl Operation {
+    /// Apply this single operation to a MultiVector
+    pub fn apply(&self, mvec: &MultiVector) -> MultiVector {
+        match self {
+            Operation::Full(operand) => full(mvec, operand),
+            Operation::FullLeft(operand) => full(operand, mvec),
+            Operation::FullWith(ops) => full(mvec, &mvec.apply_pipeline(ops)),
+            Operation::Dagger => mvec.hermitian(),
+            Operation::Reverse => mvec.reversed(),
+            Operation::Diamond => mvec.diamond(),
+            Operation::DoubleDagger => mvec.double_dagger(),
+            Operation::Dual => mvec.dual(),
+            Operation::Project(grade) => mvec.project_grade(*grade),
+            Operation::Scale(factor) => mvec.clone() * *factor,
+            Operation::Simplify => {
+                let mut m = mvec.clone();
+                m.simplify();
+                m
+            }
+        }
+    }
+}
+
+/// A record of a calculation: the starting MultiVector and each [`Operation`] applied to
+/// it in turn. Logs can be written out as JSON and re-run with [`replay`]. As with
+/// [`MultiVector::to_json`], logs are tagged with the [`SERIALIZATION_VERSION`] that they
+/// were written under.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalculationLog {
+    pub version: u32,
+    pub initial: MultiVector,
+    pub ops: Vec<Operation>,
+    #[serde(skip)]
+    current: Option<MultiVector>,
+}
+
+impl CalculationLog {
+    /// Start a new log from an initial value
+    pub fn new(initial: MultiVector) -> CalculationLog {
+        CalculationLog {
+            version: SERIALIZATION_VERSION,
+            initial,
+            ops: vec![],
+            current: None,
+        }
+    }
+
+    /// Apply op to the current value, recording it in the log and returning the result
+    pub fn apply(&mut self, op: Operation) -> MultiVector {
+        let res = op.apply(self.current.as_ref().unwrap_or(&self.initial));
+        self.ops.push(op);
+        self.current = Some(res.clone());
+
+        res
+    }
+
+    /// The result of the operations recorded so far
+    pub fn current(&self) -> MultiVector {
+        self.current.clone().unwrap_or_else(|| replay(self))
+    }
+
+    /// Serialize this log to JSON
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| e.to_string())
+    }
+
+    /// Load a log previously written by [`CalculationLog::to_json`]. Errors if the data is
+    /// malformed or was written under a different [`SERIALIZATION_VERSION`].
+    pub fn from_json(s: &str) -> Result<CalculationLog, String> {
+        let log: CalculationLog = serde_json::from_str(s).map_err(|e| e.to_string())?;
+
+        if log.version != SERIALIZATION_VERSION {
+            return Err(format!(
+                "Unsupported serialization version: found {}, expected {}",
+                log.version, SERIALIZATION_VERSION
+            ));
+        }
+
+        Ok(log)
+    }
+}
+
+impl PartialEq for CalculationLog {
+    fn eq(&self, other: &CalculationLog) -> bool {
+        self.initial == other.initial && self.ops == other.ops
+    }
+}
+
+/// Re-run each of the operations in a [`CalculationLog`] from its initial value
+pub fn replay(log: &CalculationLog) -> MultiVector {
+    log.initial.apply_pipeline(&log.ops)
+}
+
+/// Apply f to each of the inputs in turn, preserving their order. This is the standard
+/// harness for parameter sweeps over a set of MultiVectors.
+pub fn apply_to_all<F: Fn(&MultiVector) -> MultiVector>(
+    inputs: &[MultiVector],
+    f: F,
+) -> Vec<MultiVector> {
+    inputs.iter().map(f).collect()
+}
+
+/// As [`apply_to_all`] but splitting the inputs across one scoped thread per available
+/// CPU. Results are returned in the same order as the inputs.
+#[cfg(feature = "parallel")]
+pub fn apply_to_all_parallel<F: Fn(&MultiVector) -> MultiVector + Sync>(
+    inputs: &[MultiVector],
+    f: F,
+) -> Vec<MultiVector> {
+    let n_threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = inputs.len().div_ceil(n_threads).max(1);
+    let f = &f;
+
+    std::thread::scope(|s| {
+        let handles: Vec<_> = inputs
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(move || chunk.iter().map(f).collect::<Vec<_>>()))
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().expect("worker thread panicked"))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn simplified_product(left: &MultiVector, right: &MultiVector) -> MultiVector {
+        let mut res: MultiVector = full(left, right);
+        res.simplify();
+        res
+    }
+
+    #[test]
+    fn pipeline_reproduces_vdm_scalar() {
+        let m = mvec![B(), term!(0 1 2 3)];
+        let phi = simplified_product(&m, &m.hermitian());
+        let vdm_scalar = simplified_product(&phi, &phi.diamond());
+
+        let phi_ops = vec![
+            Operation::FullWith(vec![Operation::Dagger]),
+            Operation::Simplify,
+        ];
+        let mut vdm_ops = phi_ops.clone();
+        vdm_ops.extend(vec![
+            Operation::FullWith(vec![Operation::Diamond]),
+            Operation::Simplify,
+        ]);
+
+        assert_eq!(m.apply_pipeline(&phi_ops), phi);
+        assert_eq!(m.apply_pipeline(&vdm_ops), vdm_scalar);
+    }
+
+    #[test]
+    fn pipeline_applies_steps_in_order() {
+        let m = mvec![term!(), term!(2 3), term!(0 1)];
+        let ops = vec![
+            Operation::Reverse,
+            Operation::Project(2),
+            Operation::Scale(Ratio::from(-2)),
+        ];
+
+        assert_eq!(m.apply_pipeline(&ops), mvec![term!(2 3), term!(0 1)] * 2);
+        assert_eq!(m.apply_pipeline(&[]), m);
+
+        let fractional = vec![Operation::Reverse, Operation::Scale(Ratio::new(-3, 2))];
+        assert_eq!(
+            m.apply_pipeline(&fractional),
+            mvec![-term!(), term!(2 3), term!(0 1)] * Ratio::new(3, 2)
+        );
+    }
+
+    #[test]
+    fn replayed_log_matches_the_recorded_derivation() {
+        let mut log = CalculationLog::new(mvec![term!("a", 0), term!("b", 2 3)]);
+        log.apply(Operation::FullWith(vec![Operation::Dagger]));
+        log.apply(Operation::Simplify);
+        log.apply(Operation::Project(0));
+        let result = log.apply(Operation::Scale(Ratio::new(1, 2)));
+
+        let loaded = CalculationLog::from_json(&log.to_json().unwrap()).unwrap();
+
+        assert_eq!(loaded, log);
+        assert_eq!(replay(&loaded), result);
+        assert_eq!(loaded.current(), result);
+        assert!(CalculationLog::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn logs_from_other_versions_are_rejected() {
+        let mut log = CalculationLog::new(mvec![term!("a", 0)]);
+        log.apply(Operation::Dagger);
+        log.version = SERIALIZATION_VERSION + 1;
+
+        assert!(CalculationLog::from_json(&log.to_json().unwrap()).is_err());
+    }
+
+    #[test]
+    fn apply_to_all_preserves_input_order() {
+        let inputs = vec![mvec![term!(0)], mvec![term!(1 2)], mvec![term!(0 1 2 3)]];
+        let outputs = apply_to_all(&inputs, |m| m.dual());
+
+        assert_eq!(outputs, inputs.iter().map(|m| m.dual()).collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn apply_to_all_parallel_matches_serial() {
+        let inputs: Vec<MultiVector> = (0..50).map(|n| MultiVector::random(8, n)).collect();
+        let f = |m: &MultiVector| simplified_product(m, &m.hermitian());
+
+        assert_eq!(apply_to_all_parallel(&inputs, f), apply_to_all(&inputs, f));
+    }
+}
@@ -0,0 +1,87 @@
+//! Sandwich products of the form `A ^ B ^ A⁻¹` and the geometric operations that are
+//! built from them.
+
+use crate::algebra::{ar_product, div, full, Alpha, MultiVector, AR};
+
+/// Reflect target in the hyperplane orthogonal to mirror: `-n ^ v ^ n⁻¹`. mirror is expected
+/// to be a (not necessarily unit) vector.
+pub fn reflect(mirror: &MultiVector, target: &MultiVector) -> MultiVector {
+    let inverse = sandwich_inverse(mirror);
+    let nv: MultiVector = full(mirror, target);
+    let mut reflected: MultiVector = -full::<_, _, MultiVector>(&nv, &inverse);
+    reflected.simplify();
+
+    reflected
+}
+
+/// The inner automorphism `α ^ M ^ α⁻¹` by a single basis element. As α carries no Xi this
+/// is computed on the Alphas alone, leaving the Xi values of target untouched: each term is
+/// either left unchanged or negated.
+pub fn conjugate_by(basis: &Alpha, target: &MultiVector) -> MultiVector {
+    let inverse = basis.inverse();
+
+    target
+        .iter()
+        .map(|t| {
+            let mut t = t.clone();
+            t.set_alpha(ar_product(&ar_product(basis, &t.alpha()), &inverse));
+            t
+        })
+        .collect()
+}
+
+// Single terms can be inverted directly: anything else requires the Van Der Mark
+// general inverse which div applies when dividing into the identity.
+fn sandwich_inverse(m: &MultiVector) -> MultiVector {
+    if m.as_terms().len() == 1 {
+        m.inverse()
+    } else {
+        div(m, &MultiVector::identity())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::*;
+
+    #[test]
+    fn reflecting_in_a_coordinate_plane_flips_the_normal_component() {
+        let target = mvec![term!(1), term!(2), term!(0)];
+        let reflected = reflect(&mvec![term!(1)], &target);
+
+        assert_eq!(
+            reflected.blade_decomposition(),
+            vec![
+                (Sign::Pos, 1.into(), Form::Vector(Index::Zero)),
+                (Sign::Neg, 1.into(), Form::Vector(Index::One)),
+                (Sign::Pos, 1.into(), Form::Vector(Index::Two)),
+            ]
+        );
+    }
+
+    #[test]
+    fn reflecting_twice_is_the_identity() {
+        let target = mvec![term!(1), term!(2 3), term!(0)];
+        let mirror = mvec![term!(3)];
+        let twice = reflect(&mirror, &reflect(&mirror, &target));
+
+        assert_eq!(twice.blade_decomposition(), target.blade_decomposition());
+    }
+
+    #[test]
+    fn conjugating_by_a0_negates_anticommuting_forms() {
+        let a0 = alpha!(0);
+        let g = crate::prelude::G();
+        let conjugated = conjugate_by(&a0, &g);
+
+        for (before, after) in g.iter().zip(conjugated.iter()) {
+            assert_eq!(after.form(), before.form());
+            if a0.anticommutes_with(&before.alpha()) {
+                assert_eq!(after, &-before);
+            } else {
+                assert_eq!(after, before);
+            }
+        }
+    }
+}
@@ -0,0 +1,481 @@
+//! Queries over the structure of the algebra itself rather than over any particular
+//! MultiVector: which sets of elements are closed under the full product and how the
+//! basis elements multiply with one another.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::algebra::{
+    ar_product, commutator, full, signed_sum, Alpha, Form, Index, MultiVector, Ratio, Sign, Term,
+    ALLOWED_ALPHA_FORMS, AR,
+};
+
+/// Close the given generators under the full product, ignoring sign, returning (positive)
+/// alphas for each of the forms reachable sorted in the standard ordering. If the set of
+/// forms of the generators is already closed, this will simply return the generators.
+pub fn generated_subalgebra(generators: &[Alpha]) -> Vec<Alpha> {
+    let mut forms: HashSet<_> = generators.iter().map(|a| a.form()).collect();
+    let mut frontier: Vec<Alpha> = forms
+        .iter()
+        .map(|f| Alpha::new(Sign::Pos, *f).unwrap())
+        .collect();
+
+    while !frontier.is_empty() {
+        let known: Vec<Alpha> = forms
+            .iter()
+            .map(|f| Alpha::new(Sign::Pos, *f).unwrap())
+            .collect();
+
+        let mut discovered = vec![];
+        for a in frontier.iter() {
+            for b in known.iter() {
+                for product in [ar_product(a, b), ar_product(b, a)].iter() {
+                    if forms.insert(product.form()) {
+                        discovered.push(Alpha::new(Sign::Pos, product.form()).unwrap());
+                    }
+                }
+            }
+        }
+        frontier = discovered;
+    }
+
+    let mut alphas: Vec<Alpha> = forms
+        .iter()
+        .map(|f| Alpha::new(Sign::Pos, *f).unwrap())
+        .collect();
+    alphas.sort();
+    alphas
+}
+
+/// The Cayley table of the algebra as data: the sign and Form of the product of each
+/// ordered pair of basis Forms.
+pub type CayleyTable = HashMap<(Form, Form), (Sign, Form)>;
+
+/// A disagreement between two Cayley tables: the pair of Forms, our product and then
+/// the product from the reference table.
+pub type CayleyMismatch = ((Form, Form), (Sign, Form), (Sign, Form));
+
+/// The structure constants of the algebra: for each ordered pair of basis forms, the sign
+/// and form of their product under the full product. This is the Cayley table as data.
+pub fn structure_constants() -> CayleyTable {
+    let mut constants = HashMap::new();
+
+    for i in ALLOWED_ALPHA_FORMS.iter() {
+        for j in ALLOWED_ALPHA_FORMS.iter() {
+            let product = ar_product(
+                &Alpha::new(Sign::Pos, *i).unwrap(),
+                &Alpha::new(Sign::Pos, *j).unwrap(),
+            );
+            constants.insert((*i, *j), (product.sign(), product.form()));
+        }
+    }
+
+    constants
+}
+
+/// Compare our Cayley table against a reference (such as one exported from arpy), returning
+/// each pair of Forms for which the products disagree along with our value and then the
+/// reference value. Pairs missing from the reference are not reported.
+pub fn compare_cayley_to(reference: &CayleyTable) -> Vec<CayleyMismatch> {
+    let ours = structure_constants();
+
+    ALLOWED_ALPHA_FORMS
+        .iter()
+        .flat_map(|i| ALLOWED_ALPHA_FORMS.iter().map(move |j| (*i, *j)))
+        .filter_map(|key| {
+            let theirs = reference.get(&key)?;
+            let ours = ours[&key];
+
+            if &ours != theirs {
+                Some((key, ours, *theirs))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Search for a pair of non-zero MultiVectors whose full product is zero. Candidates are the
+/// sums and differences of pairs of distinct basis elements (with empty Xi values so that
+/// only the geometry is considered), searched in [`ALLOWED_ALPHA_FORMS`] order.
+pub fn find_zero_divisor_pair() -> Option<(MultiVector, MultiVector)> {
+    let basis_term = |form: &Form, sign: Sign| {
+        Term::from_xis_and_alpha(vec![], Alpha::new(sign, *form).unwrap())
+    };
+
+    let mut candidates = vec![];
+    for (i, fi) in ALLOWED_ALPHA_FORMS.iter().enumerate() {
+        for fj in ALLOWED_ALPHA_FORMS[i + 1..].iter() {
+            for sign in [Sign::Pos, Sign::Neg].iter() {
+                candidates.push(MultiVector::from_terms(vec![
+                    basis_term(fi, Sign::Pos),
+                    basis_term(fj, *sign),
+                ]));
+            }
+        }
+    }
+
+    for left in candidates.iter() {
+        for right in candidates.iter() {
+            if full::<_, _, MultiVector>(left, right).is_zero() {
+                return Some((left.clone(), right.clone()));
+            }
+        }
+    }
+
+    None
+}
+
+/// Check that the even sub-algebra (grades 0, 2 and 4) is closed under the full product
+/// by forming the product of every pair of even basis elements. The first product found
+/// to leave the even grades is reported as an error.
+pub fn verify_even_closure() -> Result<(), String> {
+    let evens: Vec<Alpha> = ALLOWED_ALPHA_FORMS
+        .iter()
+        .filter(|f| f.grade() % 2 == 0)
+        .map(|f| Alpha::new(Sign::Pos, *f).unwrap())
+        .collect();
+
+    for a in evens.iter() {
+        for b in evens.iter() {
+            let product = ar_product(a, b);
+            if product.form().grade() % 2 == 1 {
+                return Err(format!(
+                    "{} ^ {} = {} is not in the even sub-algebra",
+                    a, b, product
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// A structural fingerprint of the algebra: entry `[i][j]` is true if the basis elements
+/// at positions i and j of [`ALLOWED_ALPHA_FORMS`] anticommute and false if they commute.
+pub fn anticommutation_matrix() -> [[bool; 16]; 16] {
+    let mut matrix = [[false; 16]; 16];
+
+    for (i, fi) in ALLOWED_ALPHA_FORMS.iter().enumerate() {
+        for (j, fj) in ALLOWED_ALPHA_FORMS.iter().enumerate() {
+            let (a, b) = (
+                Alpha::new(Sign::Pos, *fi).unwrap(),
+                Alpha::new(Sign::Pos, *fj).unwrap(),
+            );
+            matrix[i][j] = a.anticommutes_with(&b);
+        }
+    }
+
+    matrix
+}
+
+/// Check that the commutator satisfies the Jacobi identity
+/// `[[a, b], c] + [[b, c], a] + [[c, a], b] = 0` for every triple of bivectors. The first
+/// triple found to violate the identity is reported as an error.
+pub fn verify_jacobi() -> Result<(), String> {
+    let bivectors: Vec<Alpha> = ALLOWED_ALPHA_FORMS
+        .iter()
+        .filter(|f| f.grade() == 2)
+        .map(|f| Alpha::new(Sign::Pos, *f).unwrap())
+        .collect();
+
+    for a in bivectors.iter() {
+        for b in bivectors.iter() {
+            for c in bivectors.iter() {
+                let mut sum = commutator(&commutator(a, b), c)
+                    + commutator(&commutator(b, c), a)
+                    + commutator(&commutator(c, a), b);
+                sum.simplify();
+
+                if sum != MultiVector::new() {
+                    return Err(format!(
+                        "Jacobi identity fails for ({}, {}, {}): {}",
+                        a, b, c, sum
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check whether the span of the Forms found in the given generators is closed under the
+/// full product. All pairs of known elements are multiplied and simplified, with new
+/// products added to the known elements for the next round, until either a product
+/// contains a Form not found in the generators, no new products are found or max_iters
+/// rounds have been run.
+pub fn is_closed_under_product(generators: &[MultiVector], max_iters: usize) -> bool {
+    let forms: HashSet<Form> = generators
+        .iter()
+        .flat_map(|g| g.iter().map(|t| t.form()))
+        .collect();
+    let mut known: Vec<MultiVector> = generators.to_vec();
+
+    for _ in 0..max_iters {
+        let mut discovered = vec![];
+        for a in known.iter() {
+            for b in known.iter() {
+                let mut product: MultiVector = full(a, b);
+                product.simplify();
+
+                if product.iter().any(|t| !forms.contains(&t.form())) {
+                    return false;
+                }
+                if !known.contains(&product) && !discovered.contains(&product) {
+                    discovered.push(product);
+                }
+            }
+        }
+
+        if discovered.is_empty() {
+            break;
+        }
+        known.extend(discovered);
+    }
+
+    true
+}
+
+/// Search for multivectors whose full product square is ap: the generalised square roots of
+/// unity in the algebra. The search covers every combination of up to max_terms distinct
+/// basis forms with each coefficient drawn from ±1 and ±1/2, so it grows as
+/// `C(16, k) * 4^k` in the number of terms k and is only practical for max_terms <= 3.
+/// Xi values are ignored when comparing the square to ap.
+pub fn find_roots_of_unity(max_terms: usize) -> Vec<MultiVector> {
+    let coeffs = [
+        Ratio::from(1),
+        Ratio::from(-1),
+        Ratio::new(1, 2),
+        Ratio::new(-1, 2),
+    ];
+    let mut unit = [Ratio::from(0); 16];
+    unit[0] = Ratio::from(1);
+
+    let mut roots = vec![];
+    let mut candidates: Vec<(usize, [Ratio; 16])> = vec![(0, [Ratio::from(0); 16])];
+
+    for _ in 0..max_terms {
+        let mut next = vec![];
+        for (start, partial) in candidates.iter() {
+            for ix in *start..16 {
+                for c in coeffs.iter() {
+                    let mut candidate = *partial;
+                    candidate[ix] = *c;
+
+                    let m = MultiVector::from_coefficients(&candidate);
+                    let square: MultiVector = full(&m, &m);
+                    if square.coefficients_as_ratios() == unit {
+                        roots.push(m);
+                    }
+                    next.push((ix + 1, candidate));
+                }
+            }
+        }
+        candidates = next;
+    }
+
+    roots
+}
+
+/// Check whether the given vectors form an orthonormal frame under the +--- metric: the
+/// symmetric inner product `<uv + vu>0 / 2` (summed over all Xi values) must be zero for
+/// each pair of distinct vectors, and for the i-th vector with itself it must be the sign
+/// that ai squares to (+ap for the first vector and -ap for the remainder). Frames of
+/// more than four vectors are rejected.
+pub fn is_orthonormal_frame(vectors: &[MultiVector]) -> bool {
+    let symmetric_inner = |u: &MultiVector, v: &MultiVector| {
+        let (uv, vu): (MultiVector, MultiVector) = (full(u, v), full(v, u));
+        let (sign, magnitude) = signed_sum(
+            (uv + vu)
+                .project_grade(0)
+                .iter()
+                .map(|t| (t.sign(), t.magnitude())),
+        );
+
+        Ratio::from_parts(sign, magnitude) / Ratio::from(2)
+    };
+    let metric: Vec<Ratio> = Index::all()
+        .iter()
+        .map(|i| {
+            let a = Alpha::new(Sign::Pos, Form::Vector(*i)).unwrap();
+            match ar_product(&a, &a).sign() {
+                Sign::Pos => Ratio::from(1),
+                Sign::Neg => Ratio::from(-1),
+            }
+        })
+        .collect();
+
+    vectors.len() <= metric.len()
+        && vectors.iter().enumerate().all(|(i, u)| {
+            vectors.iter().enumerate().all(|(j, v)| {
+                let expected = if i == j { metric[i] } else { Ratio::from(0) };
+                symmetric_inner(u, v) == expected
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::Index;
+    use crate::prelude::Even_sub_algebra;
+    use test_case::test_case;
+
+    #[test]
+    fn magnetic_bivectors_and_ap_are_closed() {
+        let generators = vec![alpha!(), alpha!(2 3), alpha!(3 1), alpha!(1 2)];
+
+        assert_eq!(generated_subalgebra(&generators), generators);
+    }
+
+    #[test]
+    fn generators_are_closed_under_product() {
+        assert_eq!(
+            generated_subalgebra(&[alpha!(0), -alpha!(1)]),
+            vec![alpha!(), alpha!(0), alpha!(1), alpha!(0 1)]
+        );
+        assert_eq!(
+            generated_subalgebra(&[alpha!(2)]),
+            vec![alpha!(), alpha!(2)]
+        );
+        assert_eq!(
+            generated_subalgebra(&[alpha!(0), alpha!(1), alpha!(2), alpha!(3)]).len(),
+            16
+        );
+    }
+
+    #[test]
+    fn structure_constants_cover_every_pair() {
+        assert_eq!(structure_constants().len(), 256);
+    }
+
+    #[test_case(alpha!(0), alpha!(1))]
+    #[test_case(alpha!(2 3), alpha!(0 1 2 3))]
+    #[test_case(alpha!(0 3 1), alpha!(0 3 1))]
+    #[test_case(alpha!(), alpha!(1 2 3))]
+    fn structure_constants_match_ar_product(a: Alpha, b: Alpha) {
+        let product = ar_product(&a, &b);
+
+        assert_eq!(
+            structure_constants()[&(a.form(), b.form())],
+            (product.sign(), product.form())
+        );
+    }
+
+    #[test]
+    fn even_sub_algebra_is_closed() {
+        assert_eq!(verify_even_closure(), Ok(()));
+    }
+
+    #[test]
+    fn anticommutation_matrix_is_symmetric_with_a_commuting_diagonal() {
+        let matrix = anticommutation_matrix();
+
+        for (i, row) in matrix.iter().enumerate() {
+            assert!(!row[i]);
+            for (j, entry) in row.iter().enumerate() {
+                assert_eq!(*entry, matrix[j][i]);
+            }
+        }
+    }
+
+    #[test]
+    fn compare_cayley_to_reports_only_the_wrong_entry() {
+        let mut reference = structure_constants();
+        let key = (alpha!(0).form(), alpha!(1).form());
+        let correct = reference[&key];
+        let wrong = (-correct.0, correct.1);
+        reference.insert(key, wrong);
+
+        assert_eq!(compare_cayley_to(&reference), vec![(key, correct, wrong)]);
+        assert_eq!(compare_cayley_to(&structure_constants()), vec![]);
+    }
+
+    #[test]
+    fn bivectors_satisfy_jacobi() {
+        assert_eq!(verify_jacobi(), Ok(()));
+    }
+
+    #[test]
+    fn ap_plus_and_minus_a0_are_zero_divisors() {
+        let ap = Term::from_xis_and_alpha(vec![], alpha!());
+        let a0 = Term::from_xis_and_alpha(vec![], alpha!(0));
+
+        assert_eq!(
+            find_zero_divisor_pair(),
+            Some((mvec![ap.clone(), a0.clone()], mvec![ap, -a0]))
+        );
+    }
+
+    #[test]
+    fn standard_basis_vectors_form_an_orthonormal_frame() {
+        let basis = [
+            mvec![term!(0)],
+            mvec![term!(1)],
+            mvec![term!(2)],
+            mvec![term!(3)],
+        ];
+        assert!(is_orthonormal_frame(&basis));
+
+        let skewed = [
+            mvec![term!(0), term!(1)],
+            mvec![term!(1)],
+            mvec![term!(2)],
+            mvec![term!(3)],
+        ];
+        assert!(!is_orthonormal_frame(&skewed));
+        assert!(!is_orthonormal_frame(&[mvec![term!(0)], mvec![term!(0)]]));
+    }
+
+    #[test]
+    fn frames_with_the_wrong_signature_are_rejected() {
+        let swapped = [
+            mvec![term!(1)],
+            mvec![term!(0)],
+            mvec![term!(2)],
+            mvec![term!(3)],
+        ];
+        let doubled = [mvec![term!(0)], mvec![term!(1) * 2usize]];
+
+        assert!(!is_orthonormal_frame(&[mvec![term!(1)]]));
+        assert!(!is_orthonormal_frame(&swapped));
+        assert!(!is_orthonormal_frame(&doubled));
+        assert!(is_orthonormal_frame(&[mvec![-term!(0)], mvec![term!(1)]]));
+    }
+
+    #[test]
+    fn even_sub_algebra_is_closed_under_product() {
+        let even: Vec<MultiVector> = Even_sub_algebra()
+            .iter()
+            .map(|t| MultiVector::from_terms(vec![t.clone()]))
+            .collect();
+        assert!(is_closed_under_product(&even, 2));
+
+        let mixed = [mvec![term!("a", 1), term!("b", 2 3)]];
+        assert!(!is_closed_under_product(&mixed, 2));
+    }
+
+    #[test]
+    fn single_blade_roots_of_unity_square_to_plus_ap() {
+        let roots = find_roots_of_unity(1);
+        let positive: Vec<Form> = ALLOWED_ALPHA_FORMS
+            .iter()
+            .filter(|f| {
+                let a = Alpha::new(Sign::Pos, **f).unwrap();
+                ar_product(&a, &a).sign() == Sign::Pos
+            })
+            .copied()
+            .collect();
+
+        assert_eq!(roots.len(), 2 * positive.len());
+        for r in roots.iter() {
+            assert_eq!(r.as_terms().len(), 1);
+            assert_eq!(r.as_terms()[0].magnitude(), 1);
+            assert!(positive.contains(&r.as_terms()[0].form()));
+        }
+        assert!(roots.contains(&mvec![term!(0)]));
+        assert!(!roots
+            .iter()
+            .any(|r| r.contains_form(&Form::Vector(Index::One))));
+    }
+}
@@ -69,6 +69,12 @@ impl Alpha {
     pub fn sign(&self) -> Sign {
         self.sign.clone()
     }
+
+    /// Check whether swapping the order of this Alpha and other in a product negates
+    /// the result. Signs of the Alphas themselves do not affect the answer.
+    pub fn anticommutes_with(&self, other: &Alpha) -> bool {
+        ar_product(self, other).sign != ar_product(other, self).sign
+    }
 }
 
 impl AR for Alpha {
@@ -102,6 +108,14 @@ impl ops::Neg for Alpha {
     }
 }
 
+impl ops::Neg for &Alpha {
+    type Output = Alpha;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
 impl fmt::Display for Alpha {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}a{}", self.sign, self.form)
@@ -120,4 +134,23 @@ mod tests {
             .collect();
         assert_eq!(forms, ALLOWED_ALPHA_STRINGS);
     }
+
+    #[test]
+    fn negating_a_reference_matches_negating_a_copy() {
+        let a = Alpha::new(Sign::Pos, Form::Vector(Index::Two)).unwrap();
+        let copied = a;
+
+        assert_eq!(-(&a), -copied);
+    }
+
+    #[test]
+    fn anticommutes_with_is_correct_for_simple_cases() {
+        let a0 = Alpha::new(Sign::Pos, Form::Vector(Index::Zero)).unwrap();
+        let a1 = Alpha::new(Sign::Neg, Form::Vector(Index::One)).unwrap();
+        let a23 = Alpha::new(Sign::Pos, Form::Bivector(Index::Two, Index::Three)).unwrap();
+
+        assert!(a0.anticommutes_with(&a1));
+        assert!(!a0.anticommutes_with(&a23));
+        assert!(!a1.anticommutes_with(&a1));
+    }
 }
@@ -0,0 +1,104 @@
+//! A serializable description of the algebra that computations were carried out in, so that
+//! saved results can be paired with the configuration needed to reproduce them.
+
+use std::collections::HashMap;
+
+use crate::algebra::types::ALLOWED_ALPHA_STRINGS;
+use crate::algebra::{ar_product, Alpha, Form, Index, Sign};
+
+/// The metric signature and orientation table of an algebra. The metric is given as one
+/// sign per basis vector (a0 to a3), i.e. "+---", and the orientations as the index order
+/// of each of the 16 allowed forms, i.e. "31" rather than "13".
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct AlgebraConfig {
+    pub metric: String,
+    pub orientations: Vec<String>,
+}
+
+impl AlgebraConfig {
+    /// The configuration of the algebra as currently implemented by [`ar_product`]
+    pub fn current() -> AlgebraConfig {
+        let metric = Index::all()
+            .iter()
+            .map(|i| {
+                let a = Alpha::new(Sign::Pos, Form::Vector(*i)).unwrap();
+                ar_product(&a, &a).sign().to_string()
+            })
+            .collect();
+
+        AlgebraConfig {
+            metric,
+            orientations: ALLOWED_ALPHA_STRINGS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+
+    /// Serialize this config to JSON
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| e.to_string())
+    }
+
+    /// Load a config previously written by [`AlgebraConfig::to_json`]
+    pub fn from_json(s: &str) -> Result<AlgebraConfig, String> {
+        serde_json::from_str(s).map_err(|e| e.to_string())
+    }
+}
+
+impl Default for AlgebraConfig {
+    fn default() -> Self {
+        AlgebraConfig::current()
+    }
+}
+
+/// The signature (p, q) of a metric given as the sign each basis vector squares to:
+/// p is the number of generators squaring to +ap and q the number squaring to -ap.
+pub fn metric_signature(metric: &HashMap<Index, Sign>) -> (usize, usize) {
+    let p = metric.values().filter(|&&s| s == Sign::Pos).count();
+
+    (p, metric.len() - p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metric_from_str(s: &str) -> HashMap<Index, Sign> {
+        Index::all()
+            .iter()
+            .zip(s.chars())
+            .map(|(&i, c)| (i, if c == '+' { Sign::Pos } else { Sign::Neg }))
+            .collect()
+    }
+
+    #[test]
+    fn metric_signature_counts_generators() {
+        assert_eq!(metric_signature(&metric_from_str("+---")), (1, 3));
+        assert_eq!(metric_signature(&metric_from_str("-+++")), (3, 1));
+        assert_eq!(
+            metric_signature(&metric_from_str(&AlgebraConfig::current().metric)),
+            (1, 3)
+        );
+    }
+
+    #[test]
+    fn current_config_matches_the_algebra() {
+        let config = AlgebraConfig::default();
+
+        assert_eq!(config.metric, "+---");
+        assert_eq!(config.orientations.len(), 16);
+        assert_eq!(config.orientations[2], "31");
+    }
+
+    #[test]
+    fn non_default_config_round_trips_through_json() {
+        let mut config = AlgebraConfig::current();
+        config.metric = "-+++".to_string();
+        config.orientations[2] = "13".to_string();
+        let json = config.to_json().unwrap();
+
+        assert_eq!(AlgebraConfig::from_json(&json), Ok(config));
+        assert!(AlgebraConfig::from_json("{}").is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use std::cmp;
+use std::convert;
 use std::fmt;
 use std::ops;
 
@@ -52,6 +53,21 @@ pub enum Index {
 }
 
 impl Index {
+    /// All four indices in order: 0, 1, 2, 3
+    pub fn all() -> [Index; 4] {
+        [Index::Zero, Index::One, Index::Two, Index::Three]
+    }
+
+    /// The 0-3 notation for this Index: the inverse of [`Index::try_from_u8`]
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            Index::Zero => 0,
+            Index::One => 1,
+            Index::Two => 2,
+            Index::Three => 3,
+        }
+    }
+
     /// Allow for construction of Index values using 0-3 notation
     pub fn try_from_u8(x: u8) -> Result<Index, String> {
         match x {
@@ -62,6 +78,25 @@ impl Index {
             _ => Err(format!("{:?} is not a valid index", x)),
         }
     }
+
+    /// Allow for construction of Index values from their string representation: "0"-"3"
+    pub fn try_from_str(s: &str) -> Result<Index, String> {
+        match s {
+            "0" => Ok(Index::Zero),
+            "1" => Ok(Index::One),
+            "2" => Ok(Index::Two),
+            "3" => Ok(Index::Three),
+            _ => Err(format!("{:?} is not a valid index", s)),
+        }
+    }
+}
+
+impl convert::TryFrom<&str> for Index {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Index::try_from_str(s)
+    }
 }
 
 impl fmt::Display for Index {
@@ -75,6 +110,20 @@ impl fmt::Display for Index {
     }
 }
 
+/// One of the four zets: the four element groupings of the 16 allowed forms, in the order
+/// they appear in [`ALLOWED_ALPHA_FORMS`].
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Debug, Copy, Clone, Serialize, Deserialize)]
+pub enum Zet {
+    /// ζB : pivot plus magnetic
+    B,
+    /// ζT : time plus spin
+    T,
+    /// ζA : hedgehog plus space
+    A,
+    /// ζE : dual-pivot plus electric
+    E,
+}
+
 /// An AR geometric form based on grade (number of ixs) involved. While any set of [`Index`]
 /// values can be used to construct a Form, those that are not found within [`ALLOWED_ALPHA_FORMS`]
 /// will result in Errors when used in calculations.
@@ -113,6 +162,41 @@ impl Form {
         }
     }
 
+    /// Attempt to construct a Form from its string representation, i.e. "p" for the
+    /// point or "023" for a trivector. This will Error if any of the characters are not
+    /// valid indices, if an index is repeated or if there are more than four indices.
+    pub fn try_from_str(s: &str) -> Result<Form, String> {
+        if s == "p" {
+            return Ok(Form::Point);
+        }
+        if s.is_empty() {
+            return Err("Empty form string".to_string());
+        }
+
+        let ixs = s
+            .chars()
+            .map(|c| Index::try_from_str(&c.to_string()))
+            .collect::<Result<Vec<Index>, String>>()?;
+
+        let mut deduped = ixs.clone();
+        deduped.sort();
+        deduped.dedup();
+        if deduped.len() != ixs.len() {
+            return Err(format!("Repeated index in form {:?}", s));
+        }
+
+        Form::try_from_indices(&ixs)
+    }
+
+    /// The Form at position ix within [`ALLOWED_ALPHA_FORMS`]: the inverse of the canonical
+    /// ordering of Forms. This will Error if ix >= 16.
+    pub fn from_canonical_index(ix: usize) -> Result<Form, String> {
+        ALLOWED_ALPHA_FORMS
+            .get(ix)
+            .copied()
+            .ok_or(format!("{} is not a valid canonical index", ix))
+    }
+
     /// Extract the underlying [`Index`] values contained in this Form.
     pub fn as_vec(&self) -> Vec<Index> {
         match *self {
@@ -123,6 +207,61 @@ impl Form {
             Form::Quadrivector(i, j, k, l) => vec![i, j, k, l],
         }
     }
+
+    /// The grade of this Form: the number of [`Index`] values it is composed of.
+    pub fn grade(&self) -> usize {
+        match *self {
+            Form::Point => 0,
+            Form::Vector(_) => 1,
+            Form::Bivector(_, _) => 2,
+            Form::Trivector(_, _, _) => 3,
+            Form::Quadrivector(_, _, _, _) => 4,
+        }
+    }
+
+    /// The zet that this Form belongs to. Forms not found in [`ALLOWED_ALPHA_FORMS`] are
+    /// classified by the allowed Form with the same indices in any order: this panics if
+    /// there is no such Form.
+    pub fn zet(&self) -> Zet {
+        let mut ixs = self.as_vec();
+        ixs.sort();
+        let pos = ALLOWED_ALPHA_FORMS
+            .iter()
+            .position(|f| {
+                let mut fixs = f.as_vec();
+                fixs.sort();
+                fixs == ixs
+            })
+            .unwrap();
+
+        [Zet::B, Zet::T, Zet::A, Zet::E][pos / 4]
+    }
+
+    /// Whether the stored index order of a Bivector or Trivector is an even (right handed)
+    /// or odd (left handed) permutation of its sorted order. None for all other Forms, for
+    /// which handedness is not meaningful.
+    pub fn is_right_handed(&self) -> Option<bool> {
+        match self {
+            Form::Bivector(_, _) | Form::Trivector(_, _, _) => {
+                let ixs = self.as_vec();
+                let inversions = (0..ixs.len())
+                    .flat_map(|i| (i + 1..ixs.len()).map(move |j| (i, j)))
+                    .filter(|&(i, j)| ixs[i] > ixs[j])
+                    .count();
+
+                Some(inversions % 2 == 0)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl convert::TryFrom<&str> for Form {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Form::try_from_str(s)
+    }
 }
 
 impl cmp::Ord for Form {
@@ -145,3 +284,94 @@ impl cmp::PartialOrd for Form {
         Some(self.cmp(other))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+    use test_case::test_case;
+
+    #[test]
+    fn as_u8_round_trips_with_try_from_u8() {
+        for (n, ix) in Index::all().iter().enumerate() {
+            assert_eq!(ix.as_u8() as usize, n);
+            assert_eq!(Index::try_from_u8(ix.as_u8()), Ok(*ix));
+        }
+    }
+
+    #[test]
+    fn zet_matches_the_prelude_groupings() {
+        use crate::algebra::AR;
+        use crate::prelude::{Zet_A, Zet_B, Zet_E, Zet_T};
+
+        for (m, zet) in [
+            (Zet_B(), Zet::B),
+            (Zet_T(), Zet::T),
+            (Zet_A(), Zet::A),
+            (Zet_E(), Zet::E),
+        ]
+        .iter()
+        {
+            for t in m.as_terms().iter() {
+                assert_eq!(t.form().zet(), *zet);
+            }
+        }
+        assert_eq!(Form::Bivector(Index::One, Index::Three).zet(), Zet::B);
+    }
+
+    #[test_case("23", Some(true))]
+    #[test_case("31", Some(false))]
+    #[test_case("12", Some(true))]
+    #[test_case("01", Some(true))]
+    #[test_case("02", Some(true))]
+    #[test_case("03", Some(true))]
+    #[test_case("023", Some(true))]
+    #[test_case("031", Some(false))]
+    #[test_case("012", Some(true))]
+    #[test_case("123", Some(true))]
+    #[test_case("p", None)]
+    #[test_case("2", None)]
+    #[test_case("0123", None)]
+    fn is_right_handed_classifies_forms(s: &str, expected: Option<bool>) {
+        assert_eq!(Form::try_from_str(s).unwrap().is_right_handed(), expected);
+    }
+
+    #[test]
+    fn from_canonical_index_follows_allowed_forms() {
+        for (ix, form) in ALLOWED_ALPHA_FORMS.iter().enumerate() {
+            assert_eq!(Form::from_canonical_index(ix), Ok(*form));
+        }
+        assert!(Form::from_canonical_index(16).is_err());
+    }
+
+    #[test_case("0", Ok(Index::Zero))]
+    #[test_case("3", Ok(Index::Three))]
+    #[test_case("5", Err("\"5\" is not a valid index".to_string()))]
+    #[test_case("01", Err("\"01\" is not a valid index".to_string()))]
+    fn index_try_from_str_works(s: &str, expected: Result<Index, String>) {
+        assert_eq!(Index::try_from_str(s), expected);
+        assert_eq!(Index::try_from(s), expected);
+    }
+
+    #[test_case("p", Form::Point)]
+    #[test_case("2", Form::Vector(Index::Two))]
+    #[test_case("31", Form::Bivector(Index::Three, Index::One))]
+    #[test_case("023", Form::Trivector(Index::Zero, Index::Two, Index::Three))]
+    #[test_case(
+        "0123",
+        Form::Quadrivector(Index::Zero, Index::One, Index::Two, Index::Three)
+    )]
+    fn form_try_from_str_parses_valid_forms(s: &str, expected: Form) {
+        assert_eq!(Form::try_from_str(s), Ok(expected));
+        assert_eq!(Form::try_from(s), Ok(expected));
+    }
+
+    #[test_case("" ; "empty string")]
+    #[test_case("5")]
+    #[test_case("00")]
+    #[test_case("0p")]
+    #[test_case("01230")]
+    fn form_try_from_str_rejects_invalid_forms(s: &str) {
+        assert!(Form::try_from_str(s).is_err());
+    }
+}
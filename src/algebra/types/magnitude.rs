@@ -11,6 +11,8 @@ use std::convert;
 use std::fmt;
 use std::ops;
 
+use crate::algebra::Sign;
+
 /// A Magnitude is a strictly positive rational number. Sign (as it pertains to directed elements)
 /// is stored in the Alpha value describine the element.
 #[derive(Hash, Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
@@ -27,6 +29,21 @@ impl Magnitude {
         r
     }
 
+    /// The numerator of this Magnitude in lowest terms
+    pub fn numerator(&self) -> usize {
+        self.numerator
+    }
+
+    /// The denominator of this Magnitude in lowest terms
+    pub fn denominator(&self) -> usize {
+        self.denominator
+    }
+
+    /// Render this Magnitude as a decimal rounded to the given number of places
+    pub fn to_decimal_string(&self, places: usize) -> String {
+        format!("{:.*}", places, f64::from(*self))
+    }
+
     fn new_unchecked(numerator: usize, denominator: usize) -> Magnitude {
         Magnitude {
             numerator,
@@ -69,6 +86,24 @@ fn gcd(n: usize, m: usize) -> usize {
     return a;
 }
 
+/// Sum a collection of signed magnitudes, returning the sign and magnitude of the result.
+/// A zero result is always given a positive sign.
+pub(crate) fn signed_sum<I: IntoIterator<Item = (Sign, Magnitude)>>(items: I) -> (Sign, Magnitude) {
+    let (pos, neg) = items.into_iter().fold(
+        (Magnitude::from(0), Magnitude::from(0)),
+        |(pos, neg), (sign, mag)| match sign {
+            Sign::Pos => (pos + mag, neg),
+            Sign::Neg => (pos, neg + mag),
+        },
+    );
+
+    if pos >= neg {
+        (Sign::Pos, pos - neg)
+    } else {
+        (Sign::Neg, neg - pos)
+    }
+}
+
 impl fmt::Display for Magnitude {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.denominator {
@@ -118,6 +153,12 @@ impl convert::From<(usize, usize)> for Magnitude {
     }
 }
 
+impl convert::From<Magnitude> for f64 {
+    fn from(m: Magnitude) -> Self {
+        m.numerator as f64 / m.denominator as f64
+    }
+}
+
 impl convert::Into<(usize, usize)> for Magnitude {
     fn into(self) -> (usize, usize) {
         (self.numerator, self.denominator)
@@ -156,6 +197,7 @@ impl ops::AddAssign for Magnitude {
         self.numerator =
             (self.numerator * other.denominator) + (other.numerator * self.denominator);
         self.denominator = self.denominator * other.denominator;
+        self.reduce();
     }
 }
 
@@ -164,6 +206,7 @@ impl ops::SubAssign for Magnitude {
         self.numerator =
             (self.numerator * other.denominator) - (other.numerator * self.denominator);
         self.denominator = self.denominator * other.denominator;
+        self.reduce();
     }
 }
 
@@ -272,6 +315,43 @@ mod tests {
         assert_eq!(left.cmp(&right), ord);
     }
 
+    #[test_case(Magnitude::new(3, 4), 0.75)]
+    #[test_case(Magnitude::new(6, 3), 2.0)]
+    #[test_case(Magnitude::new(0, 3), 0.0)]
+    fn conversion_to_f64_works(m: Magnitude, expected: f64) {
+        assert_eq!(f64::from(m), expected);
+    }
+
+    #[test_case(Magnitude::new(3, 4), 2, "0.75")]
+    #[test_case(Magnitude::new(1, 3), 2, "0.33")]
+    #[test_case(Magnitude::new(2, 3), 3, "0.667")]
+    #[test_case(Magnitude::new(5, 1), 1, "5.0")]
+    fn to_decimal_string_rounds_correctly(m: Magnitude, places: usize, expected: &str) {
+        assert_eq!(m.to_decimal_string(places), expected);
+    }
+
+    #[test]
+    fn assign_ops_keep_lowest_terms() {
+        let mut m = Magnitude::new(1, 2);
+        m += Magnitude::new(1, 2);
+        assert_eq!(
+            m,
+            Magnitude {
+                numerator: 1,
+                denominator: 1
+            }
+        );
+
+        m -= Magnitude::new(1, 4);
+        assert_eq!(
+            m,
+            Magnitude {
+                numerator: 3,
+                denominator: 4
+            }
+        );
+    }
+
     #[test]
     fn equality_works() {
         assert_eq!(Magnitude::new(2, 4), Magnitude::new(1, 2));
@@ -331,6 +411,13 @@ mod tests {
         assert_eq!(a / b, expected);
     }
 
+    #[test_case(vec![(Sign::Pos, Magnitude::new(1, 2)), (Sign::Neg, Magnitude::new(3, 4))], (Sign::Neg, Magnitude::new(1, 4)))]
+    #[test_case(vec![(Sign::Neg, Magnitude::new(1, 2)), (Sign::Pos, Magnitude::new(1, 2))], (Sign::Pos, Magnitude::new(0, 1)))]
+    #[test_case(vec![], (Sign::Pos, Magnitude::new(0, 1)))]
+    fn signed_sum_works(items: Vec<(Sign, Magnitude)>, expected: (Sign, Magnitude)) {
+        assert_eq!(signed_sum(items), expected);
+    }
+
     #[test_case(Magnitude::new(1, 2), 2, Magnitude::new(1, 4))]
     #[test_case(Magnitude::new(3, 5), 5, Magnitude::new(3, 25))]
     #[test_case(Magnitude::new(2, 5), 4, Magnitude::new(1, 10))]
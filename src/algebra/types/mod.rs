@@ -6,16 +6,23 @@
 //! certain conjugates and commutators.
 
 mod alpha;
+mod config;
 mod enums;
 mod magnitude;
 mod multivector;
+mod ratio;
 mod term;
 mod xi;
 
 pub(crate) use self::alpha::ALLOWED_ALPHA_STRINGS;
 pub use self::alpha::{Alpha, ALLOWED_ALPHA_FORMS};
-pub use self::enums::{Form, Index, Sign};
+pub use self::config::{metric_signature, AlgebraConfig};
+pub use self::enums::{Form, Index, Sign, Zet};
+pub(crate) use self::magnitude::signed_sum;
 pub use self::magnitude::Magnitude;
-pub use self::multivector::MultiVector;
+pub use self::multivector::{
+    MultiVector, MvecStats, SerializedMultiVector, SortKey, SERIALIZATION_VERSION,
+};
+pub use self::ratio::Ratio;
 pub use self::term::Term;
-pub use self::xi::Xi;
+pub use self::xi::{Xi, XI_FUNCTIONS};
@@ -1,8 +1,40 @@
-use std::collections::HashMap;
+use std::cmp;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::iter;
 use std::ops;
 
-use crate::algebra::{Form, Magnitude, Term, ALLOWED_ALPHA_FORMS, AR};
+use crate::algebra::{
+    ar_product, full, signed_sum, Alpha, Form, Index, Magnitude, Operation, Ratio, Sign, Term, Xi,
+    Zet, ALLOWED_ALPHA_FORMS, AR,
+};
+
+/// The current version of the serialized MultiVector format. This needs to be bumped
+/// whenever the serialized representation of [`Xi`], [`Term`], [`MultiVector`] or
+/// [`Operation`] changes so that stale data is rejected rather than silently misread.
+///
+/// [`Xi`]: crate::algebra::Xi
+pub const SERIALIZATION_VERSION: u32 = 1;
+
+/// A version tagged MultiVector: this is the structure that is written out by
+/// [`MultiVector::to_json`] and checked by [`MultiVector::from_json`].
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct SerializedMultiVector {
+    pub version: u32,
+    pub mvec: MultiVector,
+}
+
+/// The orderings available for presenting the terms of a MultiVector via
+/// [`MultiVector::to_sorted_terms`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SortKey {
+    /// The internal ordering used by MultiVector: by Form in zet order, then by Xi
+    Canonical,
+    /// By grade, ap through to a0123, falling back to the canonical ordering
+    ByGrade,
+    /// Largest magnitude first, falling back to the canonical ordering
+    ByMagnitudeDesc,
+}
 
 /// A MultiVector is an ordered collection of a Terms representing a particular
 /// composite quantity within the Algebra. In its simplest form, a MultiVector is
@@ -17,6 +49,16 @@ pub struct MultiVector {
     terms: Vec<Term>,
 }
 
+/// Summary statistics describing the size of a MultiVector, as returned by
+/// [`MultiVector::stats`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct MvecStats {
+    pub n_terms: usize,
+    pub n_forms: usize,
+    pub max_xi_depth: usize,
+    pub total_xi_nodes: usize,
+}
+
 impl AR for MultiVector {
     type Output = Self;
 
@@ -37,6 +79,55 @@ impl MultiVector {
         MultiVector { terms: vec![] }
     }
 
+    /// The multiplicative identity: a single unit magnitude ap term with an empty Xi
+    pub fn identity() -> MultiVector {
+        let ap = Alpha::new(Sign::Pos, Form::Point).unwrap();
+        MultiVector::from_terms(vec![Term::from_xis_and_alpha(vec![], ap)])
+    }
+
+    /// Generate a reproducible pseudo-random MultiVector of n_terms terms for use as a
+    /// benchmark workload. Forms, signs and small integer magnitudes are chosen using a
+    /// xorshift generator seeded with seed: the same seed always gives the same result.
+    pub fn random(n_terms: usize, seed: u64) -> MultiVector {
+        // xorshift can not leave the all zero state so we need a non-zero seed
+        let mut state = if seed == 0 {
+            0x9E37_79B9_7F4A_7C15
+        } else {
+            seed
+        };
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let terms = (0..n_terms)
+            .map(|_| {
+                let form = ALLOWED_ALPHA_FORMS[(next() % 16) as usize];
+                let sign = if next() % 2 == 0 {
+                    Sign::Pos
+                } else {
+                    Sign::Neg
+                };
+                let magnitude = (next() % 4 + 1) as usize;
+
+                Term::new(None, Alpha::new(sign, form).unwrap()) * magnitude
+            })
+            .collect();
+
+        MultiVector::from_terms(terms)
+    }
+
+    /// Build a MultiVector by calling f with each of the [`ALLOWED_ALPHA_FORMS`] in turn and
+    /// collecting the terms it returns.
+    pub fn from_form_fn<F: Fn(Form) -> Option<Term>>(f: F) -> MultiVector {
+        ALLOWED_ALPHA_FORMS
+            .iter()
+            .filter_map(|form| f(*form))
+            .collect()
+    }
+
     /// Returns an iterator over terms contained in this MultiVector
     pub fn iter(&self) -> MvecIterator {
         MvecIterator {
@@ -58,6 +149,20 @@ impl MultiVector {
         self.terms.sort();
     }
 
+    /// A copy of the terms in this MultiVector sorted by the given [`SortKey`]. self keeps
+    /// its canonical internal ordering.
+    pub fn to_sorted_terms(&self, by: SortKey) -> Vec<Term> {
+        let mut terms = self.terms.clone();
+
+        match by {
+            SortKey::Canonical => (),
+            SortKey::ByGrade => terms.sort_by_key(|t| t.form().grade()),
+            SortKey::ByMagnitudeDesc => terms.sort_by_key(|t| cmp::Reverse(t.magnitude())),
+        }
+
+        terms
+    }
+
     /// Extract a copy of the terms in this MultiVector that have the supplied [`Form`]
     pub fn get(&self, c: &Form) -> Option<Vec<Term>> {
         let mut terms: Vec<Term> = self
@@ -71,6 +176,744 @@ impl MultiVector {
         return if terms.len() > 0 { Some(terms) } else { None };
     }
 
+    /// Group the terms of this MultiVector by their [`Form`]. Each group is sorted in the
+    /// same way as the result of [`MultiVector::get`].
+    pub fn to_form_map(&self) -> HashMap<Form, Vec<Term>> {
+        let mut map: HashMap<Form, Vec<Term>> = HashMap::new();
+
+        self.terms.iter().cloned().for_each(|t| {
+            map.entry(t.form()).or_insert(vec![]).push(t);
+        });
+        map.values_mut().for_each(|terms| terms.sort());
+
+        map
+    }
+
+    /// Build a new MultiVector by applying f to each term, keeping only the terms for
+    /// which it returns Some.
+    pub fn filter_map_terms<F: Fn(&Term) -> Option<Term>>(&self, f: F) -> MultiVector {
+        MultiVector::from_terms(self.terms.iter().filter_map(f).collect())
+    }
+
+    /// Build a new MultiVector by applying f to each term and summing the results, with
+    /// like terms combined. This allows bespoke linear operators to be defined term by term.
+    pub fn apply_termwise<T: AR, F: Fn(&Term) -> T>(&self, f: F) -> MultiVector {
+        let mut res =
+            MultiVector::from_terms(self.terms.iter().flat_map(|t| f(t).as_terms()).collect());
+        res.simplify();
+
+        res
+    }
+
+    /// Replace the Xi of each term with the default symbol derived from its Form, keeping
+    /// the sign and magnitude of each term. This leaves only the geometric skeleton of self.
+    pub fn strip_xis(&self) -> MultiVector {
+        MultiVector::from_terms(
+            self.terms
+                .iter()
+                .map(|t| Term::new(None, t.alpha()) * t.magnitude())
+                .collect(),
+        )
+    }
+
+    /// Split this MultiVector into its positive and negative terms (in that order) without
+    /// first combining like terms.
+    pub fn partition_by_sign(&self) -> (MultiVector, MultiVector) {
+        let (pos, neg) = self
+            .terms
+            .iter()
+            .cloned()
+            .partition(|t| t.sign() == Sign::Pos);
+
+        (MultiVector::from_terms(pos), MultiVector::from_terms(neg))
+    }
+
+    /// Find the term with the given Form and Xi string: the same key used to group terms
+    /// in [`MultiVector::simplify`]. self is assumed to be simplified so that there is at
+    /// most one match.
+    pub fn find_term(&self, form: &Form, xi_str: &str) -> Option<&Term> {
+        self.terms
+            .iter()
+            .find(|t| &t.form() == form && t.xi_str() == xi_str)
+    }
+
+    /// Move the numeric coefficient of each term into its Xi so that it is no longer
+    /// collapsed by products: a term `(n/d) ξ` becomes `1 (ξn.ξ / ξd)`. Magnitudes themselves
+    /// are always plain rationals, so this is the representation used for symbolic
+    /// coefficients. The result can be evaluated numerically using [`Xi::evaluate`].
+    ///
+    /// [`Xi::evaluate`]: crate::algebra::Xi::evaluate
+    pub fn with_symbolic_coefficients(&self) -> MultiVector {
+        self.terms
+            .iter()
+            .map(|t| {
+                let mag = t.magnitude();
+                if mag == 1 {
+                    return t.clone();
+                }
+
+                let mut factors = vec![Xi::new(&mag.numerator().to_string()), t.xi()];
+                if mag.denominator() != 1 {
+                    factors
+                        .push(Xi::merge(&vec![Xi::new(&mag.denominator().to_string())]).inverse());
+                }
+
+                let mut symbolic = t.clone() / mag;
+                symbolic.set_xi(Xi::merge(&factors));
+                symbolic
+            })
+            .collect()
+    }
+
+    /// Classify this MultiVector by how it behaves under reversion: Some(Pos) if it is
+    /// self-reverse, Some(Neg) if reversing negates it and None otherwise. Like terms are
+    /// combined before comparing.
+    pub fn reversion_symmetry(&self) -> Option<Sign> {
+        let mut m = self.clone();
+        m.simplify();
+        let mut rev = m.reversed();
+        rev.simplify();
+
+        if rev == m {
+            Some(Sign::Pos)
+        } else if rev == -&m {
+            Some(Sign::Neg)
+        } else {
+            None
+        }
+    }
+
+    /// For each Form present in this MultiVector, find the greatest common [`Xi`] factor of
+    /// its terms (see [`Xi::gcd`]) and the remaining terms once that factor is removed.
+    /// Forms whose terms share no common factor are reported with an empty Xi.
+    pub fn factor_common_xi(&self) -> Vec<(Form, Xi, MultiVector)> {
+        ALLOWED_ALPHA_FORMS
+            .iter()
+            .filter_map(|form| {
+                let terms = self.get(form)?;
+                let common = terms[1..]
+                    .iter()
+                    .fold(terms[0].xi(), |acc, t| acc.gcd(&t.xi()));
+                let remaining = terms
+                    .iter()
+                    .map(|t| {
+                        let mut t = t.clone();
+                        t.set_xi(t.xi().remove_factor(&common).unwrap());
+                        t
+                    })
+                    .collect();
+
+                Some((*form, common, remaining))
+            })
+            .collect()
+    }
+
+    /// Render this MultiVector as one equation right hand side per Form present, once like
+    /// terms have been combined: the signed sum of the Xi contributions for that Form.
+    pub fn as_equations(&self) -> Vec<(Form, String)> {
+        let mut m = self.clone();
+        m.simplify();
+
+        ALLOWED_ALPHA_FORMS
+            .iter()
+            .filter_map(|form| {
+                let rhs = m
+                    .get(form)?
+                    .iter()
+                    .map(|t| {
+                        let mag = if t.magnitude() != 1 {
+                            t.magnitude().to_string()
+                        } else {
+                            String::new()
+                        };
+                        format!("{}{}{}", t.sign(), mag, t.xi_str())
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ");
+
+                Some((*form, rhs))
+            })
+            .collect()
+    }
+
+    /// Check whether this MultiVector is zero once like terms have been combined
+    pub fn is_zero(&self) -> bool {
+        let mut m = self.clone();
+        m.simplify();
+        m.terms.is_empty()
+    }
+
+    /// A single representative for all MultiVectors that are equal up to representation:
+    /// Xi values are flattened, reduced and canonicalised, like terms combined and zero terms
+    /// dropped. Two MultiVectors are equal if and only if their canonical forms are equal.
+    pub fn canonical_form(&self) -> MultiVector {
+        let mut m: MultiVector = self
+            .terms
+            .iter()
+            .map(|t| {
+                let mut t = t.clone();
+                t.set_xi(t.xi().flatten().reduce().canonical());
+                t
+            })
+            .collect();
+        m.simplify();
+
+        m
+    }
+
+    /// The root-mass part of this MultiVector: its ap and a0123 terms. Unlike a grade
+    /// projection this spans two grades (0 and 4).
+    pub fn root_mass_part(&self) -> MultiVector {
+        self.filter_map_terms(|t| match t.form() {
+            Form::Point | Form::Quadrivector(_, _, _, _) => Some(t.clone()),
+            _ => None,
+        })
+    }
+
+    /// The (ap, a0123) coefficients of this MultiVector, summed over all Xi values, as a
+    /// pseudo-complex number. Returns None unless every term is root-mass (see
+    /// [`MultiVector::root_mass_part`]).
+    pub fn as_complex_scalar(&self) -> Option<(Ratio, Ratio)> {
+        let coefficient = |grade: usize| {
+            let (sign, magnitude) = signed_sum(
+                self.terms
+                    .iter()
+                    .filter(|t| t.form().grade() == grade)
+                    .map(|t| (t.sign(), t.magnitude())),
+            );
+            Ratio::from_parts(sign, magnitude)
+        };
+
+        if self.root_mass_part().terms.len() != self.terms.len() {
+            return None;
+        }
+
+        Some((coefficient(0), coefficient(4)))
+    }
+
+    /// Split this MultiVector into its components in each of the four zets, in the order
+    /// ζB, ζT, ζA, ζE used by [`ALLOWED_ALPHA_FORMS`].
+    pub fn zet_decomposition(&self) -> [MultiVector; 4] {
+        let mut zets = [vec![], vec![], vec![], vec![]];
+
+        self.terms.iter().for_each(|t| {
+            let ix = match t.form().zet() {
+                Zet::B => 0,
+                Zet::T => 1,
+                Zet::A => 2,
+                Zet::E => 3,
+            };
+            zets[ix].push(t.clone());
+        });
+
+        let [b, t, a, e] = zets;
+        [
+            MultiVector::from_terms(b),
+            MultiVector::from_terms(t),
+            MultiVector::from_terms(a),
+            MultiVector::from_terms(e),
+        ]
+    }
+
+    /// Tabulate the action of this MultiVector on the basis: each of the 16 allowed
+    /// alphas is mapped to the simplified full product of self with that alpha.
+    pub fn basis_action(&self) -> HashMap<Alpha, MultiVector> {
+        ALLOWED_ALPHA_FORMS
+            .iter()
+            .map(|f| {
+                let alpha = Alpha::new(Sign::Pos, *f).unwrap();
+                let mut res: MultiVector = full(self, &alpha);
+                res.simplify();
+                (alpha, res)
+            })
+            .collect()
+    }
+
+    /// Summarise the size of this MultiVector: the number of terms and distinct Forms along
+    /// with the depth and total size of the Xi trees it holds. Large Xi trees are the usual
+    /// cause of slow or memory heavy calculations.
+    pub fn stats(&self) -> MvecStats {
+        MvecStats {
+            n_terms: self.terms.len(),
+            n_forms: self.to_form_map().len(),
+            max_xi_depth: self.terms.iter().map(|t| t.xi().depth()).max().unwrap_or(0),
+            total_xi_nodes: self.terms.iter().map(|t| t.xi().node_count()).sum(),
+        }
+    }
+
+    /// The set of symbols used by the Xi values of all terms in this MultiVector
+    pub fn xi_symbols(&self) -> HashSet<String> {
+        self.terms.iter().flat_map(|t| t.xi().symbols()).collect()
+    }
+
+    /// Report each (Form, Xi string) summation key that is shared by more than one term,
+    /// along with the number of terms sharing it. These are the terms that [`simplify`]
+    /// would combine, so a non-empty result for a simplified MultiVector indicates a
+    /// canonicalisation problem.
+    ///
+    /// [`simplify`]: MultiVector::simplify
+    pub fn unsimplified_groups(&self) -> Vec<(Form, String, usize)> {
+        let mut counts: HashMap<(Form, String), usize> = HashMap::new();
+        self.terms.iter().for_each(|t| {
+            *counts.entry(t.summation_key()).or_insert(0) += 1;
+        });
+
+        let mut groups: Vec<(Form, String, usize)> = counts
+            .into_iter()
+            .filter(|(_, n)| *n > 1)
+            .map(|((form, xi), n)| (form, xi, n))
+            .collect();
+        groups.sort();
+
+        groups
+    }
+
+    /// Check whether any term in this MultiVector has the supplied [`Form`]
+    pub fn contains_form(&self, form: &Form) -> bool {
+        self.terms.iter().any(|t| &t.form() == form)
+    }
+
+    /// Check whether any term in this MultiVector is of the supplied grade
+    pub fn contains_grade(&self, grade: usize) -> bool {
+        self.terms.iter().any(|t| t.form().grade() == grade)
+    }
+
+    /// The scalar (ap) part of the full product of this MultiVector with itself, summed
+    /// over all Xi values. Only pairs of terms sharing a [`Form`] can produce ap, so all
+    /// other cross terms are skipped rather than computed and then discarded.
+    pub fn self_trace(&self) -> (Sign, Magnitude) {
+        signed_sum(self.terms.iter().flat_map(|left| {
+            self.terms
+                .iter()
+                .filter(move |right| right.form() == left.form())
+                .map(move |right| {
+                    let sign = ar_product(&left.alpha(), &right.alpha()).sign();
+                    (sign, left.magnitude() * right.magnitude())
+                })
+        }))
+    }
+
+    /// The scalar part of the full product of the bivector (grade 2) part of this
+    /// MultiVector with itself, summed over all Xi values: the rotationally invariant
+    /// squared field strength for an electromagnetic style field.
+    pub fn bivector_invariant(&self) -> (Sign, Magnitude) {
+        self.project_grade(2).self_trace()
+    }
+
+    /// The parity (P) transform of this MultiVector: each of the spatial indices 1, 2 and 3
+    /// is reflected, negating every term whose Form contains an odd number of them.
+    pub fn space_inverted(&self) -> MultiVector {
+        self.reflect_indices(&[Index::One, Index::Two, Index::Three])
+    }
+
+    /// The time reversal (T) transform of this MultiVector: the time index 0 is reflected,
+    /// negating every term whose Form contains it.
+    pub fn time_reversed(&self) -> MultiVector {
+        self.reflect_indices(&[Index::Zero])
+    }
+
+    /// The combined PT transform: [`MultiVector::space_inverted`] followed by
+    /// [`MultiVector::time_reversed`].
+    pub fn pt_transformed(&self) -> MultiVector {
+        self.space_inverted().time_reversed()
+    }
+
+    /// Reflect all four indices, negating every term of odd grade
+    pub fn fully_inverted(&self) -> MultiVector {
+        self.reflect_indices(&Index::all())
+    }
+
+    // Negate each term whose Form contains an odd number of the given indices
+    fn reflect_indices(&self, indices: &[Index]) -> MultiVector {
+        MultiVector::from_terms(
+            self.terms
+                .iter()
+                .map(|t| {
+                    let n = t
+                        .form()
+                        .as_vec()
+                        .iter()
+                        .filter(|i| indices.contains(i))
+                        .count();
+                    if n % 2 == 1 {
+                        -t
+                    } else {
+                        t.clone()
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Run each of the given [`Operation`]s in turn, starting from this MultiVector
+    pub fn apply_pipeline(&self, ops: &[Operation]) -> MultiVector {
+        ops.iter().fold(self.clone(), |acc, op| op.apply(&acc))
+    }
+
+    /// Compare two MultiVectors by their signed magnitudes as f64 values, allowing for a
+    /// difference of up to `tol` for each (Form, Xi) pairing. Terms that are missing from
+    /// one side are treated as having zero magnitude.
+    pub fn approx_eq(&self, other: &MultiVector, tol: f64) -> bool {
+        let mut diffs: HashMap<(Form, String), f64> = HashMap::new();
+        let signed = |t: &Term| match t.sign() {
+            Sign::Pos => f64::from(t.magnitude()),
+            Sign::Neg => -f64::from(t.magnitude()),
+        };
+
+        self.terms.iter().for_each(|t| {
+            *diffs.entry(t.summation_key()).or_insert(0.0) += signed(t);
+        });
+        other.terms.iter().for_each(|t| {
+            *diffs.entry(t.summation_key()).or_insert(0.0) -= signed(t);
+        });
+
+        diffs.values().all(|d| d.abs() <= tol)
+    }
+
+    /// Check whether this MultiVector has the structure of a rotor: it must be non-empty,
+    /// lie entirely within the even sub-algebra and satisfy R ^ rev(R) being a pure
+    /// scalar. When R ^ rev(R) is a single term with an empty Xi it must be exactly +ap,
+    /// otherwise normalisation of the symbolic Xi values is assumed as it can not be
+    /// checked symbolically.
+    pub fn is_rotor(&self) -> bool {
+        if self.terms.is_empty() || self.terms.iter().any(|t| t.form().grade() % 2 == 1) {
+            return false;
+        }
+
+        let mut r_rev: MultiVector = full(self, &self.reversed());
+        r_rev.simplify();
+
+        match r_rev.terms.as_slice() {
+            [t] if t.xi().is_empty() => r_rev == MultiVector::identity(),
+            _ => !r_rev.terms.is_empty() && r_rev.is_scalar(),
+        }
+    }
+
+    /// The inverse of a rotor is its reverse, which is far cheaper to compute than the
+    /// general Van Der Mark inverse. Returns None if this MultiVector is not a rotor
+    /// (see [`MultiVector::is_rotor`]).
+    pub fn rotor_inverse(&self) -> Option<MultiVector> {
+        if self.is_rotor() {
+            Some(self.reversed())
+        } else {
+            None
+        }
+    }
+
+    /// The exponential of a single term generator `θα`, held symbolically using
+    /// [`XI_FUNCTIONS`]: `cos(θ) + sin(θ)α` when α squares to -ap and `cosh(θ) + sinh(θ)α`
+    /// when it squares to +ap (`exp(θ)` for ap itself). Returns None unless self is a single
+    /// unit magnitude term whose Xi is a plain symbol.
+    ///
+    /// [`XI_FUNCTIONS`]: crate::algebra::XI_FUNCTIONS
+    pub fn exp(&self) -> Option<MultiVector> {
+        if self.terms.len() != 1 || self.terms[0].magnitude() != 1 {
+            return None;
+        }
+
+        let t = &self.terms[0];
+        let xi = t.xi();
+        let theta = xi.symbol()?;
+        let ap = Alpha::new(Sign::Pos, Form::Point).unwrap();
+
+        if t.form() == Form::Point {
+            let arg = match t.sign() {
+                Sign::Pos => theta.to_string(),
+                Sign::Neg => format!("-{}", theta),
+            };
+            return Some(MultiVector::from_terms(vec![Term::new(
+                Some(&format!("exp({})", arg)),
+                ap,
+            )]));
+        }
+
+        let (even, odd) = match ar_product(&t.alpha(), &t.alpha()).sign() {
+            Sign::Neg => ("cos", "sin"),
+            Sign::Pos => ("cosh", "sinh"),
+        };
+
+        Some(MultiVector::from_terms(vec![
+            Term::new(Some(&format!("{}({})", even, theta)), ap),
+            Term::new(Some(&format!("{}({})", odd, theta)), t.alpha()),
+        ]))
+    }
+
+    /// The logarithm of a rotor `c + sα` with a single bivector α, returning the generator
+    /// `θα` such that [`MultiVector::exp`] recovers the rotor. Rotors of the form
+    /// `cos(θ) + sin(θ)α` (or `cosh(θ) + sinh(θ)α` when α squares to +ap) give θ exactly;
+    /// otherwise, when α squares to -ap, θ is held symbolically as `atan2(s, c)`. Returns
+    /// None for non-rotors and for rotors not of this form.
+    pub fn rotor_log(&self) -> Option<MultiVector> {
+        if !self.is_rotor() {
+            return None;
+        }
+
+        let (c, s) = match self.terms.as_slice() {
+            [c, s] if c.form() == Form::Point && s.form().grade() == 2 => (c, s),
+            _ => return None,
+        };
+        if c.magnitude() != 1 || s.magnitude() != 1 {
+            return None;
+        }
+
+        let (c_xi, s_xi) = (c.xi(), s.xi());
+        let (c_sym, s_sym) = (c_xi.symbol()?, s_xi.symbol()?);
+        let square = ar_product(&s.alpha(), &s.alpha()).sign();
+        let (even, odd) = match square {
+            Sign::Neg => ("cos", "sin"),
+            Sign::Pos => ("cosh", "sinh"),
+        };
+        let arg_of = |sym: &str, f: &str| {
+            sym.strip_prefix(f)
+                .and_then(|a| a.strip_prefix('('))
+                .and_then(|a| a.strip_suffix(')'))
+                .map(|a| a.to_string())
+        };
+
+        let theta = match (arg_of(c_sym, even), arg_of(s_sym, odd)) {
+            (Some(x), Some(y)) if x == y && c.sign() == Sign::Pos => x,
+            _ if square == Sign::Neg => match c.sign() {
+                Sign::Pos => format!("atan2({}, {})", s_sym, c_sym),
+                Sign::Neg => format!("atan2({}, -{})", s_sym, c_sym),
+            },
+            _ => return None,
+        };
+
+        Some(MultiVector::from_terms(vec![Term::new(
+            Some(&theta),
+            s.alpha(),
+        )]))
+    }
+
+    /// Numerically sum the first n_terms terms of the exponential series `Σ mⁿ/n!`, with Xi
+    /// values evaluated using the given bindings. Unlike [`MultiVector::exp`] this applies
+    /// to any MultiVector. Each non-zero coefficient of the result is held as a numeric
+    /// literal Xi on a unit magnitude term so it can be recovered with [`Xi::evaluate`].
+    /// Returns None if any Xi can not be evaluated.
+    pub fn exp_series(
+        &self,
+        n_terms: usize,
+        bindings: &HashMap<String, f64>,
+    ) -> Option<MultiVector> {
+        let m = self.coefficients_as_f64(bindings)?;
+        let mut power = [0.0; 16];
+        power[0] = 1.0;
+        let mut sum = [0.0; 16];
+
+        for n in 0..n_terms {
+            if n > 0 {
+                let mut next = [0.0; 16];
+                for (i, p) in power.iter().enumerate().filter(|(_, p)| **p != 0.0) {
+                    for (j, c) in m.iter().enumerate().filter(|(_, c)| **c != 0.0) {
+                        let alpha = ar_product(
+                            &Alpha::new(Sign::Pos, ALLOWED_ALPHA_FORMS[i]).unwrap(),
+                            &Alpha::new(Sign::Pos, ALLOWED_ALPHA_FORMS[j]).unwrap(),
+                        );
+                        let k = ALLOWED_ALPHA_FORMS
+                            .iter()
+                            .position(|f| *f == alpha.form())
+                            .unwrap();
+                        match alpha.sign() {
+                            Sign::Pos => next[k] += p * c / n as f64,
+                            Sign::Neg => next[k] -= p * c / n as f64,
+                        }
+                    }
+                }
+                power = next;
+            }
+            sum.iter_mut().zip(power.iter()).for_each(|(s, p)| *s += p);
+        }
+
+        Some(
+            sum.iter()
+                .zip(ALLOWED_ALPHA_FORMS.iter())
+                .filter(|(c, _)| **c != 0.0)
+                .map(|(c, f)| {
+                    let sign = if *c < 0.0 { Sign::Neg } else { Sign::Pos };
+                    Term::new(Some(&c.abs().to_string()), Alpha::new(sign, *f).unwrap())
+                })
+                .collect(),
+        )
+    }
+
+    /// Render this MultiVector in the same layout as its Display impl but with `width`
+    /// terms per line. A form switches to a multi-line layout once it has more than
+    /// one and a half lines worth of terms. A MultiVector with no non-zero terms is
+    /// rendered as `{ 0 }`.
+    pub fn display_with_width(&self, width: usize) -> String {
+        self.render(width, |term| format!("{}{}", term.sign(), term.xi_str()))
+    }
+
+    /// Render this MultiVector in the same layout as its Display impl, showing the
+    /// magnitude of each non-unit term as a decimal rounded to the given number of places.
+    pub fn display_with_decimals(&self, places: usize) -> String {
+        self.render(6, |term| {
+            let m = term.magnitude();
+            let m_str = if m != 1 {
+                m.to_decimal_string(places)
+            } else {
+                String::new()
+            };
+
+            format!("{}{}{}", term.sign(), m_str, term.xi_str())
+        })
+    }
+
+    fn render<F: Fn(&Term) -> String>(&self, width: usize, fmt_term: F) -> String {
+        if self.terms.iter().all(|t| t.magnitude() == 0) {
+            return "{ 0 }".to_string();
+        }
+
+        let n_per_line = width.max(1);
+        let mut rows = vec![];
+
+        for form in ALLOWED_ALPHA_FORMS.iter() {
+            if let Some(terms) = self.get(form) {
+                let form_rows = terms
+                    .iter()
+                    .map(&fmt_term)
+                    .collect::<Vec<String>>()
+                    .chunks(n_per_line)
+                    .map(|c| c.join(", "))
+                    .collect::<Vec<String>>();
+
+                if terms.len() < n_per_line + (n_per_line / 2) {
+                    rows.push(format!(
+                        "  a{:<5}( {} )",
+                        form.to_string(),
+                        form_rows.join(" ")
+                    ));
+                } else {
+                    rows.push(format!("  a{:<5}(", form.to_string()));
+                    form_rows
+                        .iter()
+                        .for_each(|r| rows.push(format!("           {}", r)));
+                    rows.push("  )".to_string());
+                }
+            }
+        }
+
+        format!("{{\n{}\n}}", rows.join("\n"))
+    }
+
+    /// Summarise the geometric content of this MultiVector as a signed coefficient for
+    /// each [`Form`] present once like terms have been combined. Xi values are discarded
+    /// and the coefficients of each Form summed: Forms whose coefficients cancel to zero
+    /// are omitted.
+    pub fn blade_decomposition(&self) -> Vec<(Sign, Magnitude, Form)> {
+        let mut simplified = self.clone();
+        simplified.simplify();
+
+        ALLOWED_ALPHA_FORMS
+            .iter()
+            .filter_map(|form| {
+                let terms = simplified.get(form)?;
+                let (sign, mag) = signed_sum(terms.iter().map(|t| (t.sign(), t.magnitude())));
+
+                if mag == 0 {
+                    None
+                } else {
+                    Some((sign, mag, *form))
+                }
+            })
+            .collect()
+    }
+
+    /// The signed coefficient of each of the [`ALLOWED_ALPHA_FORMS`] in canonical order,
+    /// summed over all Xi values: Forms that are not present have a zero coefficient.
+    pub fn coefficients_as_ratios(&self) -> [Ratio; 16] {
+        ALLOWED_ALPHA_FORMS.map(|form| {
+            let (sign, magnitude) = signed_sum(
+                self.terms
+                    .iter()
+                    .filter(|t| t.form() == form)
+                    .map(|t| (t.sign(), t.magnitude())),
+            );
+            Ratio::from_parts(sign, magnitude)
+        })
+    }
+
+    /// The numerical coefficient of each of the [`ALLOWED_ALPHA_FORMS`] in canonical order,
+    /// with each Xi evaluated using the given bindings (see [`Xi::evaluate`]) and summed.
+    /// Returns None if any Xi can not be evaluated.
+    pub fn coefficients_as_f64(&self, bindings: &HashMap<String, f64>) -> Option<[f64; 16]> {
+        let mut coeffs = [0.0; 16];
+
+        for t in self.terms.iter() {
+            let ix = ALLOWED_ALPHA_FORMS.iter().position(|f| *f == t.form())?;
+            let value = f64::from(t.magnitude()) * t.xi().evaluate(bindings)?;
+            match t.sign() {
+                Sign::Pos => coeffs[ix] += value,
+                Sign::Neg => coeffs[ix] -= value,
+            }
+        }
+
+        Some(coeffs)
+    }
+
+    /// Build a MultiVector from the signed coefficient of each of the [`ALLOWED_ALPHA_FORMS`]
+    /// in canonical order: the inverse of [`MultiVector::coefficients_as_ratios`]. Each
+    /// non-zero coefficient becomes a term with the default Xi value for its Form.
+    pub fn from_coefficients(coeffs: &[Ratio; 16]) -> MultiVector {
+        coeffs
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| r.magnitude() != 0)
+            .map(|(ix, r)| {
+                let form = Form::from_canonical_index(ix).unwrap();
+                Term::new(None, Alpha::new(Sign::Pos, form).unwrap()) * *r
+            })
+            .collect()
+    }
+
+    /// Serialize this MultiVector to JSON, tagged with the current [`SERIALIZATION_VERSION`]
+    pub fn to_json(&self) -> Result<String, String> {
+        let tagged = SerializedMultiVector {
+            version: SERIALIZATION_VERSION,
+            mvec: self.clone(),
+        };
+
+        serde_json::to_string(&tagged).map_err(|e| e.to_string())
+    }
+
+    /// Load a MultiVector previously written by [`MultiVector::to_json`]. Errors if the
+    /// data is malformed or was written under a different [`SERIALIZATION_VERSION`].
+    pub fn from_json(s: &str) -> Result<MultiVector, String> {
+        let tagged: SerializedMultiVector = serde_json::from_str(s).map_err(|e| e.to_string())?;
+
+        if tagged.version != SERIALIZATION_VERSION {
+            return Err(format!(
+                "Unsupported serialization version: found {}, expected {}",
+                tagged.version, SERIALIZATION_VERSION
+            ));
+        }
+
+        Ok(tagged.mvec)
+    }
+
+    /// Simplify this MultiVector, first checking that all terms grouped together by their
+    /// summation key really do have matching Xi values and not just matching string
+    /// representations. self is left untouched if the check fails.
+    pub fn simplify_checked(&mut self) -> Result<(), String> {
+        let mut groups: HashMap<(Form, String), Vec<&Term>> = HashMap::new();
+
+        self.terms.iter().for_each(|t| {
+            groups.entry(t.summation_key()).or_insert(vec![]).push(t);
+        });
+
+        for (_, group) in groups.iter() {
+            if let Some(t) = group.iter().find(|t| t.xi() != group[0].xi()) {
+                return Err(format!(
+                    "Terms {} and {} share a summation key but have differing Xi values",
+                    group[0], t
+                ));
+            }
+        }
+
+        self.simplify();
+        Ok(())
+    }
+
     /// Combine together term weights where they have matching Form and Xi
     pub fn simplify(&mut self) {
         let mut groups: HashMap<(Form, String), Vec<Term>> = HashMap::new();
@@ -108,6 +951,14 @@ impl ops::Mul<isize> for MultiVector {
     }
 }
 
+impl ops::Mul<Ratio> for MultiVector {
+    type Output = MultiVector;
+
+    fn mul(self, rhs: Ratio) -> Self::Output {
+        MultiVector::from_terms(self.terms.iter().map(|t| t.clone() * rhs).collect())
+    }
+}
+
 impl ops::Mul<MultiVector> for isize {
     type Output = MultiVector;
 
@@ -140,6 +991,22 @@ impl ops::Div<Magnitude> for MultiVector {
     }
 }
 
+impl ops::Div<isize> for MultiVector {
+    type Output = MultiVector;
+
+    fn div(self, rhs: isize) -> Self::Output {
+        MultiVector::from_terms(self.terms.iter().map(|t| t.clone() / rhs).collect())
+    }
+}
+
+impl ops::Div<Ratio> for MultiVector {
+    type Output = MultiVector;
+
+    fn div(self, rhs: Ratio) -> Self::Output {
+        MultiVector::from_terms(self.terms.iter().map(|t| t.clone() / rhs).collect())
+    }
+}
+
 impl ops::Add for MultiVector {
     type Output = MultiVector;
 
@@ -175,42 +1042,45 @@ impl ops::Neg for MultiVector {
     type Output = MultiVector;
 
     fn neg(self) -> Self::Output {
-        MultiVector::from_terms(self.terms.iter().map(|t| -t.clone()).collect())
+        MultiVector::from_terms(self.terms.iter().map(|t| -t).collect())
+    }
+}
+
+impl ops::Neg for &MultiVector {
+    type Output = MultiVector;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
     }
 }
 
 impl fmt::Display for MultiVector {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut rows = vec![];
-        let n_per_line = 6;
+        write!(f, "{}", self.display_with_width(6))
+    }
+}
 
-        for form in ALLOWED_ALPHA_FORMS.iter() {
-            if let Some(terms) = self.get(form) {
-                let form_rows = terms
-                    .iter()
-                    .map(|term| format!("{}{}", term.sign(), term.xi_str()))
-                    .collect::<Vec<String>>()
-                    .chunks(n_per_line)
-                    .map(|c| c.join(", "))
-                    .collect::<Vec<String>>();
+impl iter::FromIterator<Term> for MultiVector {
+    fn from_iter<I: IntoIterator<Item = Term>>(iter: I) -> Self {
+        MultiVector::from_terms(iter.into_iter().collect())
+    }
+}
 
-                if terms.len() < n_per_line + (n_per_line / 2) {
-                    rows.push(format!(
-                        "  a{:<5}( {} )",
-                        form.to_string(),
-                        form_rows.join(" ")
-                    ));
-                } else {
-                    rows.push(format!("  a{:<5}(", form.to_string()));
-                    form_rows
-                        .iter()
-                        .for_each(|r| rows.push(format!("           {}", r.to_string())));
-                    rows.push("  )".to_string());
-                }
-            }
-        }
+impl IntoIterator for MultiVector {
+    type Item = Term;
+    type IntoIter = std::vec::IntoIter<Term>;
 
-        write!(f, "{{\n{}\n}}", rows.join("\n"))
+    fn into_iter(self) -> Self::IntoIter {
+        self.terms.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a MultiVector {
+    type Item = &'a Term;
+    type IntoIter = MvecIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
@@ -228,3 +1098,836 @@ impl<'a> Iterator for MvecIterator<'a> {
         return item;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::Index;
+    use crate::prelude::{Fields, B, E, G};
+
+    #[test]
+    fn contains_form_finds_only_bivectors_in_fields() {
+        let f = Fields();
+
+        for form in ALLOWED_ALPHA_FORMS.iter() {
+            assert_eq!(f.contains_form(form), form.grade() == 2);
+        }
+    }
+
+    #[test]
+    fn to_form_map_matches_get_for_all_present_forms() {
+        let m = mvec![
+            term!("a", 0 1),
+            -term!("b", 0 1),
+            term!(2),
+            term!(0 1 2 3),
+            term!("c", 2)
+        ];
+        let map = m.to_form_map();
+
+        assert_eq!(map.len(), 3);
+        for form in ALLOWED_ALPHA_FORMS.iter() {
+            assert_eq!(map.get(form).cloned(), m.get(form));
+        }
+    }
+
+    fn mixed_mvec() -> MultiVector {
+        mvec![
+            term!(1 2 3) * 3usize,
+            term!(2 3),
+            term!(0) * 2usize,
+            term!(0 1 2 3) * 2usize,
+            -term!(1)
+        ]
+    }
+
+    #[test]
+    fn to_sorted_terms_canonical_matches_as_terms() {
+        let m = mixed_mvec();
+
+        assert_eq!(m.to_sorted_terms(SortKey::Canonical), m.as_terms());
+    }
+
+    #[test]
+    fn to_sorted_terms_by_grade_orders_grades() {
+        let expected = vec![
+            term!(0) * 2usize,
+            -term!(1),
+            term!(2 3),
+            term!(1 2 3) * 3usize,
+            term!(0 1 2 3) * 2usize,
+        ];
+
+        assert_eq!(mixed_mvec().to_sorted_terms(SortKey::ByGrade), expected);
+    }
+
+    #[test]
+    fn to_sorted_terms_by_magnitude_puts_largest_first() {
+        let expected = vec![
+            term!(1 2 3) * 3usize,
+            term!(0) * 2usize,
+            term!(0 1 2 3) * 2usize,
+            term!(2 3),
+            -term!(1),
+        ];
+
+        assert_eq!(
+            mixed_mvec().to_sorted_terms(SortKey::ByMagnitudeDesc),
+            expected
+        );
+    }
+
+    #[test]
+    fn negating_a_reference_matches_negating_a_clone() {
+        let m = mixed_mvec();
+
+        assert_eq!(-(&m), -m.clone());
+    }
+
+    #[test]
+    fn apply_termwise_can_reproduce_diamond() {
+        let m = mixed_mvec();
+        let diamond = m.apply_termwise(|t| match t.form() {
+            Form::Point => mvec![t.clone()],
+            _ => mvec![-t],
+        });
+        let mut expected = m.diamond();
+        expected.simplify();
+
+        assert_eq!(diamond, expected);
+    }
+
+    #[test]
+    fn filter_map_terms_matches_separate_filter_and_map() {
+        let m = mixed_mvec();
+        let one_pass = m.filter_map_terms(|t| match t.form().grade() {
+            3 => None,
+            2 => Some(-t),
+            _ => Some(t.clone()),
+        });
+
+        let filtered = MultiVector::from_terms(
+            m.iter()
+                .filter(|t| t.form().grade() != 3)
+                .cloned()
+                .collect(),
+        );
+        let mapped = MultiVector::from_terms(
+            filtered
+                .iter()
+                .map(|t| if t.form().grade() == 2 { -t } else { t.clone() })
+                .collect(),
+        );
+
+        assert_eq!(one_pass, mapped);
+    }
+
+    #[test]
+    fn random_is_determined_by_the_seed() {
+        assert_eq!(MultiVector::random(20, 42), MultiVector::random(20, 42));
+        assert_eq!(MultiVector::random(20, 42).as_terms().len(), 20);
+        assert_ne!(MultiVector::random(20, 42), MultiVector::random(20, 43));
+        assert_ne!(MultiVector::random(20, 0), MultiVector::random(20, 1));
+    }
+
+    #[test]
+    fn strip_xis_removes_differences_in_xis() {
+        let m1 = mvec![
+            term!("a", 0 1),
+            -term!(["b", "c"], 2),
+            term!("d", 1 2 3) * 2usize
+        ];
+        let m2 = mvec![term!("x", 0 1), -term!("y", 2), term!(1 2 3) * 2usize];
+
+        assert_ne!(m1, m2);
+        assert_eq!(m1.strip_xis(), m2.strip_xis());
+        assert_eq!(
+            m1.strip_xis(),
+            mvec![term!(0 1), -term!(2), term!(1 2 3) * 2usize]
+        );
+    }
+
+    #[test]
+    fn owned_and_borrowed_iteration_match_as_terms() {
+        let m = mixed_mvec();
+        let mut borrowed = vec![];
+        for t in &m {
+            borrowed.push(t.clone());
+        }
+
+        assert_eq!(borrowed, m.as_terms());
+        assert_eq!(m.clone().into_iter().collect::<Vec<Term>>(), m.as_terms());
+    }
+
+    #[test]
+    fn collecting_terms_matches_from_terms() {
+        let terms = [term!(0 1), term!(2), -term!(1 2 3), term!()];
+        let collected: MultiVector = terms.iter().map(|t| -t).collect();
+        let expected = MultiVector::from_terms(terms.iter().map(|t| -t).collect());
+
+        assert_eq!(collected, expected);
+    }
+
+    #[test]
+    fn partition_by_sign_splits_and_reunites() {
+        let m = mixed_mvec() + mvec![-term!("x", 0 1), term!("x", 0 1)];
+        let (pos, neg) = m.partition_by_sign();
+
+        assert!(pos.iter().all(|t| t.sign() == Sign::Pos));
+        assert!(neg.iter().all(|t| t.sign() == Sign::Neg));
+        assert_eq!(pos + neg, m);
+    }
+
+    #[test]
+    fn zero_multivectors_display_as_zero() {
+        let zero = term!(1).try_add(&-term!(1)).unwrap();
+
+        assert_eq!(MultiVector::new().to_string(), "{ 0 }");
+        assert_eq!(mvec![zero].to_string(), "{ 0 }");
+    }
+
+    #[test]
+    fn find_term_locates_terms_by_form_and_xi() {
+        let mut m = mvec![
+            term!("a", 0 1),
+            term!("a", 0 1),
+            term!("b", 0 1),
+            -term!("a", 2)
+        ];
+        m.simplify();
+        let form = Form::Bivector(Index::Zero, Index::One);
+
+        assert_eq!(m.find_term(&form, "ξa"), Some(&(term!("a", 0 1) * 2usize)));
+        assert_eq!(m.find_term(&form, "ξc"), None);
+        assert_eq!(m.find_term(&Form::Vector(Index::One), "ξa"), None);
+    }
+
+    #[test]
+    fn symbolic_coefficients_survive_products() {
+        let half = mvec![term!("a", 1) * Magnitude::new(1, 2)];
+        let third = mvec![term!("b", 2) * Magnitude::new(1, 3)];
+        let numeric: MultiVector = full(&half, &third);
+        let symbolic: MultiVector = full(
+            &half.with_symbolic_coefficients(),
+            &third.with_symbolic_coefficients(),
+        );
+        let bindings: HashMap<String, f64> = vec![("a".to_string(), 1.0), ("b".to_string(), 1.0)]
+            .into_iter()
+            .collect();
+        let t = &symbolic.as_terms()[0];
+
+        assert_eq!(numeric.as_terms()[0].magnitude(), Magnitude::new(1, 6));
+        assert_eq!(t.magnitude(), 1);
+        assert!((t.xi().evaluate(&bindings).unwrap() - 1.0 / 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn reversion_symmetry_classifies_elements() {
+        let scalar = mvec![
+            Term::new(Some("a"), alpha!()),
+            Term::new(Some("b"), alpha!())
+        ];
+
+        assert_eq!(scalar.reversion_symmetry(), Some(Sign::Pos));
+        assert_eq!(
+            mvec![term!(2 3), -term!(0 1)].reversion_symmetry(),
+            Some(Sign::Neg)
+        );
+        assert_eq!(mvec![term!(), term!(2 3)].reversion_symmetry(), None);
+    }
+
+    #[test]
+    fn factor_common_xi_extracts_shared_factors() {
+        let m = mvec![
+            term!(["a", "x"], 0 1),
+            -term!(["b", "x"], 0 1),
+            term!(["x", "x"], 0 1),
+            term!("y", 2)
+        ];
+        let factored = m.factor_common_xi();
+
+        assert_eq!(factored.len(), 2);
+        assert_eq!(factored[0].0, Form::Vector(Index::Two));
+        assert_eq!(factored[0].1, Xi::new("y"));
+        assert_eq!(factored[1].0, Form::Bivector(Index::Zero, Index::One));
+        assert_eq!(factored[1].1, Xi::new("x"));
+        assert_eq!(
+            factored[1].2,
+            mvec![term!("a", 0 1), -term!("b", 0 1), term!("x", 0 1)]
+        );
+    }
+
+    #[test]
+    fn as_equations_gives_one_rhs_per_form() {
+        let field = mvec![term!("a", 2 3), term!("b", 0 1)];
+        let eqs = crate::prelude::DG().left_apply(&field).as_equations();
+
+        assert_eq!(eqs.len(), 16);
+        assert_eq!(eqs[0], (Form::Point, "+∂23ξa +∂01ξb".to_string()));
+        assert_eq!(
+            eqs[2],
+            (
+                Form::Bivector(Index::Three, Index::One),
+                "-∂12ξa -∂03ξb".to_string()
+            )
+        );
+        assert_eq!(
+            mvec![term!("a", 1), term!("a", 1), -term!("b", 1)].as_equations(),
+            vec![(Form::Vector(Index::One), "+2ξa -ξb".to_string())]
+        );
+    }
+
+    #[test]
+    fn is_zero_only_holds_when_terms_cancel() {
+        assert!(MultiVector::new().is_zero());
+        assert!(mvec![term!("a", 0 1), -term!("a", 0 1)].is_zero());
+        assert!(!mvec![term!("a", 0 1), -term!("b", 0 1)].is_zero());
+    }
+
+    #[test]
+    fn equal_multivectors_share_a_canonical_form() {
+        let built = [
+            mvec![term!("a", 1) * 2usize, term!("b", 2 3)],
+            mvec![term!(["a"], 1), term!("a", 1), term!(["b"], 2 3)],
+            mvec![
+                term!("a", 1) * 3usize,
+                -term!("a", 1),
+                term!("c", 2),
+                -term!("c", 2),
+                term!("b", 2 3)
+            ],
+        ];
+
+        for m in built.iter() {
+            assert_eq!(m.canonical_form(), built[0]);
+        }
+    }
+
+    #[test]
+    fn canonical_form_cancels_common_xi_factors() {
+        let a_inv = Xi::merge(&vec![Xi::new("a")]).inverse();
+        let mut quotient = term!(1);
+        quotient.set_xi(Xi::merge(&vec![Xi::new("a"), Xi::new("b"), a_inv]));
+
+        assert_ne!(mvec![quotient.clone()], mvec![term!("b", 1)]);
+        assert_eq!(
+            mvec![quotient.clone()].canonical_form(),
+            mvec![term!("b", 1)].canonical_form()
+        );
+        assert!((mvec![quotient] - mvec![term!("b", 1)])
+            .canonical_form()
+            .is_zero());
+    }
+
+    #[test]
+    fn dividing_by_a_ratio_divides_each_term() {
+        let m = mvec![term!("a", 1), -term!("b", 0 2) * 3usize];
+
+        assert_eq!(
+            m.clone() / Ratio::new(-3, 2),
+            mvec![
+                -term!("a", 1) * Magnitude::new(2, 3),
+                term!("b", 0 2) * 2usize
+            ]
+        );
+        assert_eq!(m.clone() / 1, m);
+    }
+
+    #[test]
+    fn root_mass_part_of_g_is_ap_and_a0123() {
+        assert_eq!(
+            crate::prelude::G().root_mass_part(),
+            mvec![term!(), term!(0 1 2 3)]
+        );
+    }
+
+    #[test]
+    fn as_complex_scalar_returns_root_mass_coefficients() {
+        let m = mvec![
+            Term::new(Some("a"), alpha!()) * 2usize,
+            -Term::new(Some("b"), alpha!()),
+            -term!("c", 0 1 2 3) / 3
+        ];
+
+        assert_eq!(
+            m.as_complex_scalar(),
+            Some((Ratio::from(1), Ratio::new(-1, 3)))
+        );
+        assert_eq!((m + mvec![term!("d", 0 1)]).as_complex_scalar(), None);
+    }
+
+    #[test]
+    fn zet_decomposition_splits_fields_into_b_and_e() {
+        let [b, t, a, e] = Fields().zet_decomposition();
+
+        assert_eq!(b, crate::prelude::B());
+        assert_eq!(t, MultiVector::new());
+        assert_eq!(a, MultiVector::new());
+        assert_eq!(e, crate::prelude::E());
+    }
+
+    #[test]
+    fn zet_decomposition_parts_reunite_to_the_original() {
+        let m = crate::prelude::G() + mixed_mvec();
+        let [b, t, a, e] = m.zet_decomposition();
+
+        assert_eq!(b + t + a + e, m);
+    }
+
+    #[test]
+    fn from_form_fn_places_a_symbol_on_every_bivector() {
+        let m = MultiVector::from_form_fn(|f| {
+            if f.grade() == 2 {
+                Some(Term::new(Some("phi"), Alpha::new(Sign::Pos, f).unwrap()))
+            } else {
+                None
+            }
+        });
+
+        assert_eq!(
+            m,
+            mvec![
+                term!("phi", 2 3),
+                term!("phi", 3 1),
+                term!("phi", 1 2),
+                term!("phi", 0 1),
+                term!("phi", 0 2),
+                term!("phi", 0 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn contains_grade_finds_only_grade_two_in_fields() {
+        let f = Fields();
+
+        assert!(f.contains_grade(2));
+        for grade in [0, 1, 3, 4].iter() {
+            assert!(!f.contains_grade(*grade));
+        }
+        assert!(!f.contains_form(&Form::Vector(Index::Zero)));
+    }
+
+    #[test]
+    fn self_trace_matches_scalar_part_of_full_product() {
+        let m = mvec![
+            term!("a", 0),
+            term!("b", 0),
+            -term!(1 2),
+            term!(0 1 2 3),
+            term!(0 2) * 2usize
+        ];
+        let product: MultiVector = full(&m, &m);
+        let scalar_part = product
+            .project(&Form::Point)
+            .as_terms()
+            .iter()
+            .map(|t| (t.sign(), t.magnitude()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(m.self_trace(), signed_sum(scalar_part));
+    }
+
+    #[test]
+    fn bivector_invariant_matches_hand_computation() {
+        // a0i square to +ap and aij square to -ap so the E and B fields cancel
+        assert_eq!(
+            Fields().bivector_invariant(),
+            (Sign::Pos, Magnitude::from(0))
+        );
+
+        let m = mvec![
+            term!("e", 0 1) * 2usize,
+            term!("b", 2 3),
+            term!("t", 0),
+            term!(0 1 2 3)
+        ];
+        assert_eq!(m.bivector_invariant(), (Sign::Pos, Magnitude::from(3)));
+
+        let b_only = Fields().project_grade(2) - mvec![term!("01", 0 1)];
+        assert_eq!(b_only.bivector_invariant(), (Sign::Neg, Magnitude::from(1)));
+    }
+
+    #[test]
+    fn space_inversion_flips_odd_spatial_forms() {
+        let unchanged = mvec![term!(), term!(0), term!(2 3), term!(0 2 3)];
+        let flipped = mvec![term!(1), term!(0 1), term!(1 2 3), term!(0 1 2 3)];
+
+        assert_eq!(unchanged.space_inverted(), unchanged);
+        assert_eq!(flipped.space_inverted(), -flipped.clone());
+        assert_eq!(G().space_inverted().space_inverted(), G());
+    }
+
+    #[test]
+    fn pt_is_the_composition_of_p_and_t() {
+        let m = mvec![
+            term!("a", 0),
+            term!("b", 2 3),
+            term!("c", 0 1),
+            term!("d", 0 1 2 3)
+        ];
+
+        assert_eq!(m.pt_transformed(), m.space_inverted().time_reversed());
+        assert_eq!(m.pt_transformed(), m.fully_inverted());
+        assert_eq!(G().fully_inverted().fully_inverted(), G());
+        assert!(G()
+            .fully_inverted()
+            .iter()
+            .all(|t| (t.sign() == Sign::Neg) == (t.form().grade() % 2 == 1)));
+    }
+
+    #[test]
+    fn time_reversal_flips_electric_but_not_magnetic_fields() {
+        assert_eq!(E().time_reversed(), -E());
+        assert_eq!(B().time_reversed(), B());
+        assert_eq!(
+            mvec![term!(), term!(1 2 3)].time_reversed(),
+            mvec![term!(), term!(1 2 3)]
+        );
+    }
+
+    #[test]
+    fn stats_report_xi_tree_sizes() {
+        let mut inner = Xi::merge(&vec![Xi::new("a"), Xi::new("b")]);
+        inner.add_partial(&Form::Vector(Index::Zero));
+        let mut nested = term!(0 1);
+        nested.set_xi(Xi::merge(&vec![inner, Xi::new("c")]).inverse());
+
+        let m = mvec![nested, term!("d", 0 1), term!("e", 2)];
+        let expected = MvecStats {
+            n_terms: 3,
+            n_forms: 2,
+            max_xi_depth: 3,
+            total_xi_nodes: 7,
+        };
+
+        assert_eq!(m.stats(), expected);
+    }
+
+    #[test]
+    fn unsimplified_groups_reports_shared_keys() {
+        let mut m = mvec![
+            term!("a", 1),
+            term!("a", 1),
+            -term!("a", 1),
+            term!("b", 1),
+            term!("c", 0 2),
+            term!("c", 0 2)
+        ];
+
+        assert_eq!(
+            m.unsimplified_groups(),
+            vec![
+                (Form::Vector(Index::One), "ξa".to_string(), 3),
+                (Form::Bivector(Index::Zero, Index::Two), "ξc".to_string(), 2),
+            ]
+        );
+
+        m.simplify();
+        assert_eq!(m.unsimplified_groups(), vec![]);
+    }
+
+    #[test]
+    fn display_with_width_wraps_at_the_requested_width() {
+        let m = mvec![
+            term!("a", 0 1),
+            term!("b", 0 1),
+            term!("c", 0 1),
+            term!("d", 0 1),
+            term!("e", 0 1),
+            term!("f", 0 1),
+            term!("g", 0 1)
+        ];
+        let expected = [
+            "{",
+            "  a01   (",
+            "           +ξa, +ξb, +ξc",
+            "           +ξd, +ξe, +ξf",
+            "           +ξg",
+            "  )",
+            "}",
+        ];
+
+        assert_eq!(m.display_with_width(3), expected.join("\n"));
+        assert_eq!(format!("{}", m), m.display_with_width(6));
+    }
+
+    #[test]
+    fn display_with_decimals_shows_non_unit_magnitudes() {
+        let m = mvec![
+            term!("a", 0 1) / 3,
+            -term!("b", 0 1) * Magnitude::new(3, 4),
+            term!("c", 2)
+        ];
+        let expected = ["{", "  a2    ( +ξc )", "  a01   ( +0.33ξa, -0.75ξb )", "}"];
+
+        assert_eq!(m.display_with_decimals(2), expected.join("\n"));
+    }
+
+    #[test]
+    fn rotor_inverse_of_unit_bivector_is_its_reverse() {
+        let r = mvec![term!(1 2)];
+        let r_inv = r.rotor_inverse().unwrap();
+        let product: MultiVector = full(&r, &r_inv);
+
+        assert_eq!(r_inv, r.reversed());
+        assert_eq!(product.as_alphas(), vec![alpha!()]);
+    }
+
+    #[test]
+    fn rotor_composed_with_inverse_approx_eq_identity() {
+        let r = mvec![Term::from_xis_and_alpha(vec![], alpha!(1 2))];
+        let product: MultiVector = full(&r, &r.rotor_inverse().unwrap());
+
+        assert!(product.approx_eq(&MultiVector::identity(), 1e-12));
+        assert!(!product.approx_eq(&-MultiVector::identity(), 1e-12));
+    }
+
+    #[test]
+    fn approx_eq_respects_tolerance() {
+        let m = mvec![term!("a", 0 1), term!("a", 0 1), -term!("b", 2 3)];
+        let tiny = Magnitude::new(1, 1000);
+        let nudged = m.clone() + term!("a", 0 1) * tiny;
+
+        assert!(m.approx_eq(&nudged, 1e-2));
+        assert!(!m.approx_eq(&nudged, 1e-4));
+        assert!(!m.approx_eq(&MultiVector::new(), 1e-12));
+    }
+
+    #[test]
+    fn rotor_inverse_rejects_non_rotors() {
+        assert_eq!(mvec![term!(1)].rotor_inverse(), None);
+        assert_eq!(mvec![term!(1 2), term!(0)].rotor_inverse(), None);
+        assert_eq!(MultiVector::new().rotor_inverse(), None);
+    }
+
+    #[test]
+    fn rotor_inverse_rejects_non_unit_numeric_elements() {
+        let numeric = |alpha: Alpha| Term::from_xis_and_alpha(vec![], alpha);
+
+        let doubled = mvec![numeric(alpha!()) * 2usize];
+        let unnormalised = mvec![numeric(alpha!()), numeric(alpha!(1 2))];
+        let negative = mvec![numeric(alpha!(0 1))];
+
+        assert_eq!(doubled.rotor_inverse(), None);
+        assert_eq!(unnormalised.rotor_inverse(), None);
+        assert_eq!(negative.rotor_inverse(), None);
+    }
+
+    #[test]
+    fn exp_of_a_bivector_is_held_symbolically() {
+        let r = mvec![term!("theta", 1 2)].exp().unwrap();
+        let bindings = map! { "theta".to_string() => 0.3 };
+        let values: Vec<Option<f64>> = r.iter().map(|t| t.xi().evaluate(&bindings)).collect();
+
+        assert_eq!(
+            r,
+            mvec![
+                Term::new(Some("cos(theta)"), alpha!()),
+                term!("sin(theta)", 1 2)
+            ]
+        );
+        assert_eq!(values, vec![Some(0.3f64.cos()), Some(0.3f64.sin())]);
+    }
+
+    #[test]
+    fn rotor_log_inverts_exp() {
+        let b = mvec![term!("theta", 1 2)];
+        let boost = mvec![-term!("phi", 0 1)];
+
+        assert_eq!(b.exp().unwrap().rotor_log(), Some(b));
+        assert_eq!(boost.exp().unwrap().rotor_log(), Some(boost));
+    }
+
+    #[test]
+    fn rotor_log_falls_back_to_atan2() {
+        let r = mvec![
+            -Term::new(Some("c"), alpha!()),
+            Term::new(Some("s"), alpha!(2 3))
+        ];
+        let log = r.rotor_log().unwrap();
+
+        assert_eq!(log, mvec![term!("atan2(s, -c)", 2 3)]);
+        let bindings = map! { "c".to_string() => 0.0, "s".to_string() => 1.0 };
+        assert_eq!(
+            log.as_terms()[0].xi().evaluate(&bindings),
+            Some(std::f64::consts::FRAC_PI_2)
+        );
+    }
+
+    #[test]
+    fn rotor_log_rejects_non_rotors() {
+        assert_eq!(mvec![term!("theta", 0)].rotor_log(), None);
+        assert_eq!(mvec![term!("a", 1 2), term!("b", 2 3)].rotor_log(), None);
+    }
+
+    #[test]
+    fn exp_uses_hyperbolic_functions_for_positive_squares() {
+        let r = mvec![-term!("phi", 0 1)].exp().unwrap();
+
+        assert_eq!(
+            r,
+            mvec![
+                Term::new(Some("cosh(phi)"), alpha!()),
+                -term!("sinh(phi)", 0 1)
+            ]
+        );
+        assert_eq!(mvec![term!(0 1), term!(2 3)].exp(), None);
+        assert_eq!(
+            mvec![-Term::new(Some("t"), alpha!())].exp().unwrap(),
+            mvec![Term::new(Some("exp(-t)"), alpha!())]
+        );
+    }
+
+    #[test]
+    fn exp_series_converges_to_the_closed_form() {
+        let bindings = map! { "theta".to_string() => 0.7, "phi".to_string() => 0.4 };
+        let none = HashMap::new();
+
+        for b in [mvec![term!("theta", 1 2)], mvec![-term!("phi", 0 1)]].iter() {
+            let closed = b.exp().unwrap().coefficients_as_f64(&bindings).unwrap();
+            let series = b.exp_series(20, &bindings).unwrap();
+            let summed = series.coefficients_as_f64(&none).unwrap();
+
+            assert!(closed
+                .iter()
+                .zip(summed.iter())
+                .all(|(c, s)| (c - s).abs() < 1e-12));
+            let truncated = b.exp_series(3, &bindings).unwrap();
+            let truncated = truncated.coefficients_as_f64(&none).unwrap();
+            assert!(closed
+                .iter()
+                .zip(truncated.iter())
+                .any(|(c, s)| (c - s).abs() > 1e-3));
+        }
+    }
+
+    #[test]
+    fn exp_series_requires_bound_xis() {
+        let m = mvec![term!("theta", 1 2), term!("x", 0)];
+
+        assert_eq!(m.exp_series(5, &HashMap::new()), None);
+        assert_eq!(
+            MultiVector::new().exp_series(5, &HashMap::new()),
+            Some(MultiVector::from_terms(vec![Term::new(
+                Some("1"),
+                alpha!()
+            )]))
+        );
+    }
+
+    #[test]
+    fn blade_decomposition_sums_over_xis() {
+        let v = mvec![term!(1), term!(2), term!(0 1)];
+        let mut product: MultiVector = full(&v, &v);
+        product.simplify();
+
+        assert_eq!(
+            product.blade_decomposition(),
+            vec![
+                (Sign::Neg, Magnitude::new(1, 1), Form::Point),
+                (
+                    Sign::Pos,
+                    Magnitude::new(2, 1),
+                    Form::Trivector(Index::Zero, Index::One, Index::Two)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn coefficients_as_ratios_gives_signed_coefficients_in_canonical_order() {
+        let mut m = mvec![
+            term!("a", 0 1) * 2usize,
+            -term!("b", 3) / 2,
+            -term!("c", 3),
+            term!("d", 2 3)
+        ];
+        m.simplify();
+
+        let mut expected = [Ratio::from(0); 16];
+        expected[1] = Ratio::from(1);
+        expected[11] = Ratio::new(-3, 2);
+        expected[13] = Ratio::from(2);
+
+        assert_eq!(m.coefficients_as_ratios(), expected);
+    }
+
+    #[test]
+    fn from_coefficients_round_trips_with_coefficients_as_ratios() {
+        let mut m = mvec![
+            term!(0 1) * 2usize,
+            -term!(3) / 2,
+            term!(2 3),
+            -term!(0 1 2 3) / 5,
+            term!()
+        ];
+        m.simplify();
+
+        assert_eq!(
+            MultiVector::from_coefficients(&m.coefficients_as_ratios()),
+            m
+        );
+    }
+
+    #[test]
+    fn blade_decomposition_drops_cancelled_forms() {
+        let v = mvec![term!(0), term!(1)];
+        let product: MultiVector = full(&v, &v);
+
+        assert_eq!(product.blade_decomposition(), vec![]);
+    }
+
+    #[test]
+    fn simplify_checked_rejects_structurally_different_xis() {
+        let mut m = mvec![term!("foo", 0 2), term!(["foo"], 0 2)];
+        let original = m.clone();
+
+        assert!(m.simplify_checked().is_err());
+        assert_eq!(m, original);
+    }
+
+    #[test]
+    fn simplify_checked_matches_simplify() {
+        let mut m = mvec![term!("foo", 0 2), term!("foo", 0 2), -term!(["a", "b"], 1)];
+        let mut expected = m.clone();
+        expected.simplify();
+
+        assert_eq!(m.simplify_checked(), Ok(()));
+        assert_eq!(m, expected);
+    }
+
+    #[test]
+    fn json_round_trip_uses_current_version() {
+        let m = mvec![term!("a", 0 1), -term!(["b", "c"], 2 3)];
+        let json = m.to_json().unwrap();
+        let tagged: SerializedMultiVector = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(tagged.version, SERIALIZATION_VERSION);
+        assert_eq!(MultiVector::from_json(&json), Ok(m));
+    }
+
+    #[test]
+    fn from_json_rejects_unknown_versions() {
+        let tagged = SerializedMultiVector {
+            version: SERIALIZATION_VERSION + 1,
+            mvec: mvec![term!(0 1)],
+        };
+        let json = serde_json::to_string(&tagged).unwrap();
+
+        assert!(MultiVector::from_json(&json).is_err());
+    }
+
+    #[test]
+    fn basis_action_of_ap_is_the_identity() {
+        let action = MultiVector::identity().basis_action();
+
+        assert_eq!(action.len(), 16);
+        for (alpha, res) in action.iter() {
+            let terms = res.as_terms();
+            assert_eq!(terms.len(), 1);
+            assert_eq!(terms[0].alpha(), *alpha);
+            assert_eq!(terms[0].magnitude(), 1);
+        }
+    }
+}
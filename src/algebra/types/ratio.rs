@@ -0,0 +1,149 @@
+//! Ratio is a signed rational number built on top of [`Magnitude`]. Magnitudes are kept strictly
+//! non-negative with signs carried by Alphas, so Ratio is the type to reach for when a signed
+//! coefficient needs to be passed around before it is applied to a Term. A zero Ratio is always
+//! positive.
+
+use std::convert;
+use std::fmt;
+use std::ops;
+
+use crate::algebra::{signed_sum, Magnitude, Sign};
+
+/// A signed rational number: a [`Sign`] paired with an unsigned [`Magnitude`].
+#[derive(Hash, Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct Ratio {
+    sign: Sign,
+    magnitude: Magnitude,
+}
+
+impl Ratio {
+    /// Construct a new Ratio in lowest terms, taking the sign from the numerator
+    pub fn new(numerator: isize, denominator: usize) -> Ratio {
+        let sign = if numerator < 0 { Sign::Neg } else { Sign::Pos };
+
+        Ratio::from_parts(sign, Magnitude::new(numerator.unsigned_abs(), denominator))
+    }
+
+    /// Construct a Ratio from an explicit sign and magnitude
+    pub fn from_parts(sign: Sign, magnitude: Magnitude) -> Ratio {
+        let sign = if magnitude == 0 { Sign::Pos } else { sign };
+
+        Ratio { sign, magnitude }
+    }
+
+    /// The sign of this Ratio
+    pub fn sign(&self) -> Sign {
+        self.sign
+    }
+
+    /// The unsigned magnitude of this Ratio
+    pub fn magnitude(&self) -> Magnitude {
+        self.magnitude
+    }
+}
+
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.sign {
+            Sign::Pos => write!(f, "{}", self.magnitude),
+            Sign::Neg => write!(f, "-{}", self.magnitude),
+        }
+    }
+}
+
+impl convert::From<isize> for Ratio {
+    fn from(num: isize) -> Self {
+        Ratio::new(num, 1)
+    }
+}
+
+impl convert::From<Magnitude> for Ratio {
+    fn from(magnitude: Magnitude) -> Self {
+        Ratio::from_parts(Sign::Pos, magnitude)
+    }
+}
+
+impl ops::Neg for Ratio {
+    type Output = Ratio;
+
+    fn neg(self) -> Self::Output {
+        Ratio::from_parts(-self.sign, self.magnitude)
+    }
+}
+
+impl ops::Add for Ratio {
+    type Output = Ratio;
+
+    fn add(self, rhs: Ratio) -> Self::Output {
+        let (sign, magnitude) =
+            signed_sum([(self.sign, self.magnitude), (rhs.sign, rhs.magnitude)]);
+
+        Ratio::from_parts(sign, magnitude)
+    }
+}
+
+impl ops::Sub for Ratio {
+    type Output = Ratio;
+
+    fn sub(self, rhs: Ratio) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl ops::Mul for Ratio {
+    type Output = Ratio;
+
+    fn mul(self, rhs: Ratio) -> Self::Output {
+        Ratio::from_parts(self.sign.combine(&rhs.sign), self.magnitude * rhs.magnitude)
+    }
+}
+
+impl ops::Div for Ratio {
+    type Output = Ratio;
+
+    fn div(self, rhs: Ratio) -> Self::Output {
+        Ratio::from_parts(self.sign.combine(&rhs.sign), self.magnitude / rhs.magnitude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(-3, 6, Sign::Neg, Magnitude::new(1, 2))]
+    #[test_case(4, 2, Sign::Pos, Magnitude::new(2, 1))]
+    #[test_case(0, 5, Sign::Pos, Magnitude::new(0, 1))]
+    fn new_splits_sign_and_reduces(num: isize, den: usize, sign: Sign, mag: Magnitude) {
+        let r = Ratio::new(num, den);
+
+        assert_eq!((r.sign(), r.magnitude()), (sign, mag));
+    }
+
+    #[test]
+    fn zero_is_always_positive() {
+        assert_eq!(-Ratio::from(0), Ratio::from(0));
+        assert_eq!(Ratio::from_parts(Sign::Neg, 0.into()).sign(), Sign::Pos);
+    }
+
+    #[test_case(Ratio::new(1, 2), Ratio::new(-1, 3), Ratio::new(1, 6) ; "positive result")]
+    #[test_case(Ratio::new(-1, 2), Ratio::new(1, 3), Ratio::new(-1, 6) ; "negative result")]
+    #[test_case(Ratio::new(-1, 2), Ratio::new(1, 2), Ratio::from(0) ; "zero result")]
+    fn addition_respects_signs(left: Ratio, right: Ratio, expected: Ratio) {
+        assert_eq!(left + right, expected);
+        assert_eq!(expected - right, left);
+    }
+
+    #[test]
+    fn multiplication_and_division_combine_signs() {
+        assert_eq!(Ratio::new(-2, 3) * Ratio::new(-3, 4), Ratio::new(1, 2));
+        assert_eq!(Ratio::new(-2, 3) / Ratio::new(4, 3), Ratio::new(-1, 2));
+        assert_eq!(Ratio::from(0) * Ratio::from(-5), Ratio::from(0));
+    }
+
+    #[test]
+    fn display_includes_sign() {
+        assert_eq!(Ratio::new(-2, 3).to_string(), "-2/3");
+        assert_eq!(Ratio::new(5, 1).to_string(), "5");
+    }
+}
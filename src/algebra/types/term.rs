@@ -1,16 +1,23 @@
 use std::cmp;
 use std::fmt;
+use std::hash;
 use std::ops;
 
-use crate::algebra::{ar_product, Alpha, Form, Magnitude, Sign, Xi, AR};
+use crate::algebra::{ar_product, Alpha, Form, Magnitude, Ratio, Sign, Xi, AR};
 
 /// A Term represents a real scalar magnitude along with a paired [`Alpha`] giving the
 /// proper Space-Time [`Form`] in accordence with the principle of Absolute Relativity.
-#[derive(Hash, Eq, Debug, PartialEq, Clone, Serialize, Deserialize)]
+///
+/// Terms may optionally carry a provenance string describing how they were produced.
+/// This is purely a debugging aid: it is not serialized and is ignored when comparing
+/// or hashing Terms.
+#[derive(Eq, Debug, Clone, Serialize, Deserialize)]
 pub struct Term {
     magnitude: Magnitude,
     alpha: Alpha,
     xi: Xi,
+    #[serde(skip)]
+    provenance: Option<String>,
 }
 
 impl AR for Term {
@@ -33,6 +40,7 @@ impl AR for Term {
             magnitude: 1 / self.magnitude,
             alpha: self.alpha.inverse(),
             xi: self.xi.inverse(),
+            provenance: self.provenance.clone(),
         }
     }
 }
@@ -51,6 +59,7 @@ impl Term {
             magnitude: 1.into(),
             alpha: alpha,
             xi: xi,
+            provenance: None,
         }
     }
 
@@ -60,6 +69,7 @@ impl Term {
             magnitude: 1.into(),
             alpha: alpha,
             xi: Xi::merge(&xis.iter().map(|s| Xi::new(s)).collect()),
+            provenance: None,
         }
     }
 
@@ -78,6 +88,23 @@ impl Term {
         self.alpha.clone()
     }
 
+    /// Extract a copy of the symbolic [`Xi`] value of this Term
+    pub fn xi(&self) -> Xi {
+        self.xi.clone()
+    }
+
+    /// Attach a description of where this Term came from. Provenance is carried through
+    /// products so that the origin of terms in a long derivation can be traced.
+    pub fn with_provenance(mut self, provenance: &str) -> Term {
+        self.provenance = Some(provenance.to_string());
+        self
+    }
+
+    /// Extract a copy of the provenance of this Term if it has one
+    pub fn provenance(&self) -> Option<String> {
+        self.provenance.clone()
+    }
+
     /// Extract the unsigned [`Magnitude`] of this Term
     pub fn magnitude(&self) -> Magnitude {
         self.magnitude
@@ -88,7 +115,13 @@ impl Term {
         self.alpha = a;
     }
 
-    /// Add a single partial derivative and resort
+    /// Override the symbolic Xi value of this Term
+    pub fn set_xi(&mut self, xi: Xi) {
+        self.xi = xi;
+    }
+
+    /// Add a single partial derivative and resort. Partials with respect to ap are the
+    /// identity and are skipped.
     pub fn add_partial(&mut self, wrt: &Alpha) {
         self.xi.add_partial(&wrt.form())
     }
@@ -136,12 +169,28 @@ impl Term {
         }
     }
 
-    /// Form the product of this term and another under the full product of the algebra
+    /// Form the product of this term and another under the full product of the algebra.
+    /// If either term has a provenance then the result records both of its parents.
     pub fn form_product_with(&self, other: &Term) -> Term {
+        self.form_product_with_alpha(other, ar_product(&self.alpha, &other.alpha))
+    }
+
+    // As form_product_with but using an already computed product of the two Alphas
+    pub(crate) fn form_product_with_alpha(&self, other: &Term, alpha: Alpha) -> Term {
+        let provenance = match (&self.provenance, &other.provenance) {
+            (None, None) => None,
+            (l, r) => Some(format!(
+                "({}) ^ ({})",
+                l.clone().unwrap_or_else(|| self.to_string()),
+                r.clone().unwrap_or_else(|| other.to_string())
+            )),
+        };
+
         Term {
             magnitude: self.magnitude * other.magnitude,
-            alpha: ar_product(&self.alpha, &other.alpha),
+            alpha,
             xi: Xi::merge(&vec![self.xi.clone(), other.xi.clone()]),
+            provenance,
         }
     }
 
@@ -189,6 +238,18 @@ impl ops::Mul<Magnitude> for Term {
     }
 }
 
+impl ops::Mul<Ratio> for Term {
+    type Output = Self;
+
+    fn mul(self, rhs: Ratio) -> Self::Output {
+        let t = self * rhs.magnitude();
+        match rhs.sign() {
+            Sign::Pos => t,
+            Sign::Neg => -t,
+        }
+    }
+}
+
 impl ops::Div<Magnitude> for Term {
     type Output = Term;
 
@@ -199,6 +260,26 @@ impl ops::Div<Magnitude> for Term {
     }
 }
 
+impl ops::Div<isize> for Term {
+    type Output = Term;
+
+    fn div(self, rhs: isize) -> Self::Output {
+        self / Ratio::from(rhs)
+    }
+}
+
+impl ops::Div<Ratio> for Term {
+    type Output = Term;
+
+    fn div(self, rhs: Ratio) -> Self::Output {
+        let t = self / rhs.magnitude();
+        match rhs.sign() {
+            Sign::Pos => t,
+            Sign::Neg => -t,
+        }
+    }
+}
+
 impl ops::Neg for Term {
     type Output = Term;
 
@@ -209,6 +290,14 @@ impl ops::Neg for Term {
     }
 }
 
+impl ops::Neg for &Term {
+    type Output = Term;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
 // NOTE: flipped variants for primary impls above
 
 impl ops::Mul<Term> for usize {
@@ -247,6 +336,20 @@ impl fmt::Display for Term {
     }
 }
 
+impl cmp::PartialEq for Term {
+    fn eq(&self, other: &Self) -> bool {
+        self.magnitude == other.magnitude && self.alpha == other.alpha && self.xi == other.xi
+    }
+}
+
+impl hash::Hash for Term {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.magnitude.hash(state);
+        self.alpha.hash(state);
+        self.xi.hash(state);
+    }
+}
+
 impl cmp::Ord for Term {
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         self.form()
@@ -292,6 +395,55 @@ mod tests {
         assert_eq!(left.form_product_with(&right), expected)
     }
 
+    #[test]
+    fn provenance_is_combined_through_products() {
+        let left = term!("a", 2 3).with_provenance("F");
+        let right = term!("b", 1 2 3).with_provenance("G");
+        let unlabelled = term!("c", 0);
+
+        assert_eq!(
+            left.form_product_with(&right).provenance(),
+            Some("(F) ^ (G)".to_string())
+        );
+        assert_eq!(
+            left.form_product_with(&unlabelled).provenance(),
+            Some("(F) ^ (+a0(ξc))".to_string())
+        );
+        assert_eq!(unlabelled.form_product_with(&unlabelled).provenance(), None);
+    }
+
+    #[test]
+    fn negating_a_reference_matches_negating_a_clone() {
+        let t = term!("a", 0 3 1);
+
+        assert_eq!(-(&t), -t.clone());
+    }
+
+    #[test]
+    fn multiplying_by_a_negative_ratio_flips_alpha() {
+        let t = term!("a", 0 1) * Magnitude::new(3, 4);
+
+        assert_eq!(
+            t.clone() * Ratio::new(-2, 3),
+            -term!("a", 0 1) * Magnitude::new(1, 2)
+        );
+        assert_eq!(t * Ratio::new(1, 3), term!("a", 0 1) * Magnitude::new(1, 4));
+    }
+
+    #[test]
+    fn dividing_by_a_negative_integer_flips_alpha() {
+        assert_eq!(term!("a", 2) / -2, -term!("a", 2) * Magnitude::new(1, 2));
+        assert_eq!(
+            term!("a", 2) / Ratio::new(3, 2),
+            term!("a", 2) * Magnitude::new(2, 3)
+        );
+    }
+
+    #[test]
+    fn provenance_is_ignored_for_equality() {
+        assert_eq!(term!("a", 2 3).with_provenance("F"), term!("a", 2 3));
+    }
+
     // #[test]
     // fn form_product_with_works_inversion() {
     //     let left = term!("a", 2 3);
@@ -1,11 +1,16 @@
 use std::cmp;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::ops;
 
 use crate::algebra::types::alpha::ALLOWED_ALPHA_STRINGS;
 use crate::algebra::Form;
 
+/// Functions that are recognised by [`Xi::evaluate`] when they appear in a symbol,
+/// allowing transcendental coefficients such as `cos(theta)` to be held symbolically.
+/// `atan2(y, x)` takes two comma separated arguments, all others take one.
+pub const XI_FUNCTIONS: [&str; 7] = ["cos", "sin", "cosh", "sinh", "exp", "sqrt", "atan2"];
+
 #[derive(Hash, Eq, Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Xi {
     value: Option<String>, // None for non-leaf nodes
@@ -49,15 +54,80 @@ impl Xi {
         self == &Xi::empty()
     }
 
-    /// Add a single partial derivative to this Xi
+    /// The raw symbol of this Xi if it is a single symbolic value with no partials
+    pub fn symbol(&self) -> Option<&str> {
+        match (&self.value, self.partials.len()) {
+            (Some(v), 0) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Attempt to numerically evaluate this Xi using the supplied values for its symbols.
+    /// Symbols may also be numeric literals or one of the [`XI_FUNCTIONS`] applied to
+    /// something that can itself be evaluated, e.g. `cos(theta)`. Returns None if any
+    /// symbol is unbound or if there are partial derivatives to account for.
+    pub fn evaluate(&self, bindings: &HashMap<String, f64>) -> Option<f64> {
+        if !self.partials.is_empty() {
+            return None;
+        }
+
+        if let Some(v) = &self.value {
+            return evaluate_symbol(v, bindings);
+        }
+
+        let product = |xis: &Vec<Xi>| -> Option<f64> {
+            xis.iter()
+                .try_fold(1.0, |acc, x| x.evaluate(bindings).map(|v| acc * v))
+        };
+
+        Some(product(&self.child_num)? / product(&self.child_den)?)
+    }
+
+    /// The depth of the tree of Xi values making up this Xi: 1 for a leaf
+    pub fn depth(&self) -> usize {
+        1 + self
+            .child_num
+            .iter()
+            .chain(self.child_den.iter())
+            .map(|x| x.depth())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The total number of Xi values in the tree making up this Xi, including itself
+    pub fn node_count(&self) -> usize {
+        1 + self
+            .child_num
+            .iter()
+            .chain(self.child_den.iter())
+            .map(|x| x.node_count())
+            .sum::<usize>()
+    }
+
+    /// The set of symbols appearing as leaf values anywhere in the tree making up this Xi
+    pub fn symbols(&self) -> HashSet<String> {
+        let mut symbols: HashSet<String> = self.value.iter().cloned().collect();
+        self.child_num
+            .iter()
+            .chain(self.child_den.iter())
+            .for_each(|x| symbols.extend(x.symbols()));
+
+        symbols
+    }
+
+    /// Add a single partial derivative to this Xi. Differentiating with respect to ap is
+    /// the identity so partials with respect to [`Form::Point`] are skipped.
     pub fn add_partial(&mut self, wrt: &Form) {
-        self.partials.push(*wrt);
-        self.partials.sort();
+        if wrt != &Form::Point {
+            self.partials.push(*wrt);
+            self.partials.sort();
+        }
     }
 
-    /// Replace the current set of partial derivatives
+    /// Replace the current set of partial derivatives. As with [`Xi::add_partial`], any
+    /// partials with respect to [`Form::Point`] are skipped.
     pub fn set_partials(&mut self, partials: Vec<Form>) {
-        self.partials = partials;
+        self.partials = partials.into_iter().filter(|f| f != &Form::Point).collect();
         self.partials.sort();
     }
 
@@ -91,6 +161,155 @@ impl Xi {
         }
     }
 
+    /// A canonical representation of this Xi: a product wrapping a single factor is replaced
+    /// by that factor and all children are themselves canonicalised and sorted.
+    pub(crate) fn canonical(&self) -> Xi {
+        if self.value.is_none()
+            && self.partials.is_empty()
+            && self.child_num.len() == 1
+            && self.child_den.is_empty()
+        {
+            return self.child_num[0].canonical();
+        }
+
+        let canonicalise = |xis: &Vec<Xi>| {
+            let mut res: Vec<Xi> = xis.iter().map(|x| x.canonical()).collect();
+            res.sort();
+            res
+        };
+
+        Xi {
+            value: self.value.clone(),
+            partials: self.partials.clone(),
+            child_num: canonicalise(&self.child_num),
+            child_den: canonicalise(&self.child_den),
+        }
+    }
+
+    /// Normalise this Xi to a single level of numerator and denominator factors by
+    /// recursively pulling up the children of all nested empty parents (merge only pulls
+    /// up a single level). Leaf values and values with partials are kept as factors but
+    /// have their own children flattened.
+    pub fn flatten(&self) -> Xi {
+        let (mut num, mut den) = (vec![], vec![]);
+        self.collect_factors(&mut num, &mut den);
+
+        if self.value.is_none() && self.partials.is_empty() {
+            Xi::from_factors(num, den)
+        } else {
+            num.sort();
+            den.sort();
+            Xi {
+                value: self.value.clone(),
+                partials: self.partials.clone(),
+                child_num: num,
+                child_den: den,
+            }
+        }
+    }
+
+    // Push the flattened children of this Xi on to num and den, recursing through any
+    // empty parents (swapping num and den for those found in the denominator).
+    fn collect_factors(&self, num: &mut Vec<Xi>, den: &mut Vec<Xi>) {
+        let is_empty_parent = |x: &Xi| x.value.is_none() && x.partials.is_empty();
+
+        for x in self.child_num.iter() {
+            if is_empty_parent(x) {
+                x.collect_factors(num, den);
+            } else {
+                num.push(x.flatten());
+            }
+        }
+        for x in self.child_den.iter() {
+            if is_empty_parent(x) {
+                x.collect_factors(den, num);
+            } else {
+                den.push(x.flatten());
+            }
+        }
+    }
+
+    /// Cancel factors that appear in both the numerator and denominator of this Xi, as with
+    /// reducing a fraction. Factors must match exactly, including their partials. A Xi that
+    /// cancels completely reduces to the empty (unit) Xi.
+    pub fn reduce(&self) -> Xi {
+        let (num, mut den) = self.factors();
+        let num: Vec<Xi> = num
+            .into_iter()
+            .filter(|x| match den.iter().position(|y| y == x) {
+                Some(i) => {
+                    den.remove(i);
+                    false
+                }
+                None => true,
+            })
+            .collect();
+
+        Xi::from_factors(num, den)
+    }
+
+    /// The greatest common factor of two Xi values, found by intersecting the multisets of
+    /// factors in their numerators and denominators. Leaf values and values with partials
+    /// are treated as a single factor. Returns an empty Xi if nothing is shared.
+    pub fn gcd(&self, other: &Xi) -> Xi {
+        let (lnum, lden) = self.factors();
+        let (rnum, rden) = other.factors();
+
+        let intersect = |left: Vec<Xi>, mut right: Vec<Xi>| -> Vec<Xi> {
+            left.into_iter()
+                .filter(|x| match right.iter().position(|y| y == x) {
+                    Some(i) => {
+                        right.remove(i);
+                        true
+                    }
+                    None => false,
+                })
+                .collect()
+        };
+
+        Xi::from_factors(intersect(lnum, rnum), intersect(lden, rden))
+    }
+
+    /// Remove each of the factors of factor from this Xi, returning None if this Xi
+    /// does not contain all of them.
+    pub(crate) fn remove_factor(&self, factor: &Xi) -> Option<Xi> {
+        let (mut num, mut den) = self.factors();
+        let (fnum, fden) = factor.factors();
+
+        for (factors, to_remove) in [(&mut num, fnum), (&mut den, fden)].iter_mut() {
+            for x in to_remove.iter() {
+                let i = factors.iter().position(|y| y == x)?;
+                factors.remove(i);
+            }
+        }
+
+        Some(Xi::from_factors(num, den))
+    }
+
+    // The numerator and denominator factors of this Xi
+    fn factors(&self) -> (Vec<Xi>, Vec<Xi>) {
+        if self.value.is_none() && self.partials.is_empty() {
+            (self.child_num.clone(), self.child_den.clone())
+        } else {
+            (vec![self.clone()], vec![])
+        }
+    }
+
+    fn from_factors(mut num: Vec<Xi>, mut den: Vec<Xi>) -> Xi {
+        if num.len() == 1 && den.is_empty() {
+            return num.remove(0);
+        }
+        num.sort();
+        den.sort();
+
+        Xi {
+            value: None,
+            partials: Vec::new(),
+            child_num: num,
+            child_den: den,
+        }
+    }
+
     /// Represent this Xi as a dotted string of terms
     pub fn dotted_string(&self) -> String {
         let partials = partial_str(&self.partials);
@@ -128,7 +347,7 @@ impl Xi {
         match self.value.clone() {
             Some(val) => format!("{}ξ{}", partials, val),
             None => match (self.child_num.len(), self.child_den.len()) {
-                (0, 0) => panic!("Empty Xi"),
+                (0, 0) => with_partials("1".to_string()),
                 (_, 0) => with_partials(power_notation(&self.child_num)),
                 (0, _) => with_partials(format!("1/{}", power_notation(&self.child_num))),
                 (_, _) => with_partials(format!(
@@ -141,6 +360,35 @@ impl Xi {
     }
 }
 
+#[cfg(any(test, feature = "test-support"))]
+impl Xi {
+    /// Check that the product of Xi values is commutative and associative over all pairs
+    /// and triples of the given samples, reporting the first violation found.
+    pub fn check_algebra_laws(samples: &[Xi]) -> Result<(), String> {
+        for a in samples.iter() {
+            for b in samples.iter() {
+                let (ab, ba) = (a.clone() * b.clone(), b.clone() * a.clone());
+                if ab != ba {
+                    return Err(format!("{} * {} != {} * {}: {} != {}", a, b, b, a, ab, ba));
+                }
+
+                for c in samples.iter() {
+                    let left = ab.clone() * c.clone();
+                    let right = a.clone() * (b.clone() * c.clone());
+                    if left != right {
+                        return Err(format!(
+                            "({} * {}) * {} != {} * ({} * {}): {} != {}",
+                            a, b, c, a, b, c, left, right
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl fmt::Display for Xi {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.dotted_string())
@@ -187,6 +435,54 @@ impl cmp::PartialOrd for Xi {
     }
 }
 
+fn evaluate_symbol(s: &str, bindings: &HashMap<String, f64>) -> Option<f64> {
+    if let Some(v) = bindings.get(s) {
+        return Some(*v);
+    }
+
+    if let Some(negated) = s.strip_prefix('-') {
+        return evaluate_symbol(negated, bindings).map(|v| -v);
+    }
+
+    for name in XI_FUNCTIONS.iter() {
+        if s.starts_with(name) && s[name.len()..].starts_with('(') && s.ends_with(')') {
+            let inner = &s[name.len() + 1..s.len() - 1];
+            if *name == "atan2" {
+                let (y, x) = split_arguments(inner)?;
+                return Some(evaluate_symbol(y, bindings)?.atan2(evaluate_symbol(x, bindings)?));
+            }
+            let arg = evaluate_symbol(inner, bindings)?;
+
+            return Some(match *name {
+                "cos" => arg.cos(),
+                "sin" => arg.sin(),
+                "cosh" => arg.cosh(),
+                "sinh" => arg.sinh(),
+                "exp" => arg.exp(),
+                _ => arg.sqrt(),
+            });
+        }
+    }
+
+    s.parse::<f64>().ok()
+}
+
+// Split the arguments of a two argument function such as atan2 on the first comma that is
+// not nested inside of parentheses.
+fn split_arguments(s: &str) -> Option<(&str, &str)> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => return Some((s[..i].trim(), s[i + 1..].trim())),
+            _ => (),
+        }
+    }
+
+    None
+}
+
 // Concatenate the forms represeneting the partial derivatives applied to this Xi
 pub(super) fn partial_str(partials: &Vec<Form>) -> String {
     partials
@@ -197,8 +493,130 @@ pub(super) fn partial_str(partials: &Vec<Form>) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::algebra::Index;
     use test_case::test_case;
 
+    fn nested_quotient() -> Xi {
+        let mut inner = Xi::merge(&vec![Xi::new("a"), Xi::new("b")]);
+        inner.add_partial(&Form::Vector(Index::Zero));
+
+        Xi::merge(&vec![inner, Xi::new("c")]).inverse()
+    }
+
+    #[test]
+    fn flatten_pulls_up_nested_empty_parents() {
+        let parent = |num: Vec<Xi>, den: Vec<Xi>| Xi {
+            value: None,
+            partials: Vec::new(),
+            child_num: num,
+            child_den: den,
+        };
+        let nested = parent(
+            vec![parent(
+                vec![Xi::new("a"), parent(vec![Xi::new("b")], vec![Xi::new("c")])],
+                vec![],
+            )],
+            vec![parent(vec![Xi::new("d")], vec![Xi::new("e")])],
+        );
+        let expected = parent(
+            vec![Xi::new("a"), Xi::new("b"), Xi::new("e")],
+            vec![Xi::new("c"), Xi::new("d")],
+        );
+
+        assert_eq!(nested.depth(), 4);
+        assert_eq!(nested.flatten(), expected);
+        assert_eq!(nested.flatten().depth(), 2);
+        assert_eq!(nested_quotient().flatten(), nested_quotient());
+    }
+
+    #[test]
+    fn partials_with_respect_to_ap_are_skipped() {
+        let mut x = Xi::new("a");
+        x.add_partial(&Form::Point);
+        assert_eq!(x, Xi::new("a"));
+
+        x.set_partials(vec![
+            Form::Vector(Index::Two),
+            Form::Point,
+            Form::Vector(Index::Zero),
+        ]);
+        assert_eq!(
+            x.partials,
+            vec![Form::Vector(Index::Zero), Form::Vector(Index::Two)]
+        );
+    }
+
+    #[test]
+    fn reduce_cancels_common_factors() {
+        let a = Xi::new("a");
+        let b = Xi::new("b");
+        let over_a = Xi::merge(&vec![a.clone()]).inverse();
+
+        let ab_over_a = Xi::merge(&vec![a.clone(), b.clone(), over_a.clone()]);
+        assert_eq!(ab_over_a.reduce(), b);
+
+        let a_over_a = Xi::merge(&vec![a.clone(), over_a]);
+        assert_eq!(a_over_a.reduce(), Xi::empty());
+        assert!(a_over_a.reduce().is_empty());
+    }
+
+    #[test]
+    fn reduce_only_cancels_matching_partials() {
+        let mut da = Xi::new("a");
+        da.add_partial(&Form::Vector(Index::Zero));
+        let x = Xi::merge(&vec![da, Xi::merge(&vec![Xi::new("a")]).inverse()]);
+
+        assert_eq!(x.reduce(), x);
+    }
+
+    #[test]
+    fn products_satisfy_the_algebra_laws() {
+        let (a, b, c) = (Xi::new("a"), Xi::new("b"), Xi::new("c"));
+        let mut da = a.clone();
+        da.add_partial(&Form::Vector(Index::Zero));
+        let mut d_ac = a.clone() * c.clone();
+        d_ac.add_partial(&Form::Vector(Index::One));
+
+        let samples = [
+            Xi::empty(),
+            a.clone(),
+            b.clone(),
+            da,
+            a.clone() * b.clone(),
+            Xi::merge(&vec![a.clone(), Xi::merge(&vec![b.clone()]).inverse()]),
+            Xi::merge(&vec![c]).inverse(),
+            d_ac,
+            nested_quotient(),
+        ];
+
+        assert_eq!(Xi::check_algebra_laws(&samples), Ok(()));
+    }
+
+    #[test]
+    fn depth_and_node_count_walk_the_whole_tree() {
+        assert_eq!((Xi::new("a").depth(), Xi::new("a").node_count()), (1, 1));
+
+        let x = nested_quotient();
+        assert_eq!(x.depth(), 3);
+        assert_eq!(x.node_count(), 5);
+    }
+
+    #[test]
+    fn gcd_finds_shared_factors() {
+        let a = Xi::merge(&vec![Xi::new("a"), Xi::new("x"), Xi::new("x")]);
+        let b = Xi::merge(&vec![Xi::new("x"), Xi::new("b"), Xi::new("x")]);
+        let c = Xi::merge(&vec![Xi::new("x"), Xi::new("c")]);
+
+        assert_eq!(a.gcd(&b), Xi::merge(&vec![Xi::new("x"), Xi::new("x")]));
+        assert_eq!(a.gcd(&c), Xi::new("x"));
+        assert_eq!(a.gcd(&Xi::new("d")), Xi::empty());
+        assert_eq!(
+            a.remove_factor(&Xi::new("x")),
+            Some(Xi::merge(&vec![Xi::new("a"), Xi::new("x")]))
+        );
+        assert_eq!(c.remove_factor(&Xi::new("a")), None);
+    }
+
     #[test_case(
         vec![Xi::new("foo"), Xi::new("bar")],
         Xi {
@@ -335,4 +753,43 @@ mod tests {
     fn merge_with_partials_on_children_works(xis: Vec<Xi>, expected: Xi) {
         assert_eq!(Xi::merge(&xis), expected);
     }
+
+    #[test_case(Xi::new("theta"), Some(0.5))]
+    #[test_case(Xi::new("cos(theta)"), Some(0.5f64.cos()))]
+    #[test_case(Xi::new("sqrt(cosh(2))"), Some(2f64.cosh().sqrt()))]
+    #[test_case(Xi::merge(&vec![Xi::new("theta"), Xi::new("4")]), Some(2.0))]
+    #[test_case(Xi::merge(&vec![Xi::new("theta"), Xi::new("4")]).inverse(), Some(0.5))]
+    #[test_case(Xi::new("exp(-theta)"), Some((-0.5f64).exp()))]
+    #[test_case(Xi::new("atan2(sin(theta), cos(theta))"), Some(0.5))]
+    #[test_case(Xi::new("atan2(1, -theta)"), Some(1f64.atan2(-0.5)))]
+    #[test_case(Xi::new("atan2(theta)"), None)]
+    #[test_case(Xi::new("phi"), None)]
+    #[test_case(Xi::new("tan(theta)"), None)]
+    fn evaluate_works(xi: Xi, expected: Option<f64>) {
+        let bindings = map! { "theta".to_string() => 0.5 };
+        assert_eq!(xi.evaluate(&bindings), expected);
+    }
+
+    #[test]
+    fn empty_xi_is_displayed_as_unit() {
+        assert_eq!(Xi::empty().to_string(), "1");
+        assert_eq!(Xi::merge(&vec![Xi::empty(), Xi::empty()]).to_string(), "1");
+    }
+
+    #[test]
+    fn evaluate_rejects_partials() {
+        let mut xi = Xi::new("theta");
+        xi.add_partial(&alpha!(0).form());
+        let bindings = map! { "theta".to_string() => 0.5 };
+
+        assert_eq!(xi.evaluate(&bindings), None);
+    }
+
+    #[test]
+    fn symbols_are_collected_from_the_whole_tree() {
+        let expected: HashSet<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+
+        assert_eq!(nested_quotient().symbols(), expected);
+        assert!(Xi::empty().symbols().is_empty());
+    }
 }
@@ -3,7 +3,7 @@ extern crate arthroprod;
 
 use std::collections::HashSet;
 
-use arthroprod::algebra::{full, Form, MultiVector, AR};
+use arthroprod::algebra::{full, van_der_mark_scalar, Form, MultiVector, AR};
 use arthroprod::prelude::*;
 
 /// Helper for forming products and simplifying the resulting terms
@@ -36,7 +36,6 @@ fn main() {
     let squared = |m: &MultiVector| simplified_product(m, |n| n.clone());
     let phi = |m: &MultiVector| simplified_product(m, |n| n.hermitian());
     let ddaggered = |m: &MultiVector| simplified_product(m, |n| n.double_dagger());
-    let vdm_scalar = |m: &MultiVector| simplified_product(&phi(&m), |n| n.diamond());
 
     let time_like = vec![term!(), term!(0), term!(1 2 3), term!(0 1 2 3)];
     let space_like = vec![B(), T(), A(), E()];
@@ -49,7 +48,7 @@ fn main() {
             print_if_scalar("squared", squared(&mvec));
             print_if_scalar("phi", phi(&mvec));
             print_if_scalar("double_dagger", ddaggered(&mvec));
-            print_if_scalar("VdM_scalar", vdm_scalar(&mvec));
+            print_if_scalar("VdM_scalar", van_der_mark_scalar(&mvec));
             println!("\n");
         }
     }
@@ -9,5 +9,9 @@ extern crate serde_derive;
 #[macro_use]
 pub mod macros;
 
+#[cfg(any(test, feature = "test-support"))]
+#[macro_use]
+pub mod test_support;
+
 pub mod algebra;
 pub mod prelude;
@@ -1,9 +1,11 @@
 //! Common data structures and operators used in the algebra
 #![allow(non_snake_case, non_upper_case_globals)]
 
+use std::collections::HashSet;
+
 use crate::algebra::types::ALLOWED_ALPHA_STRINGS;
 use crate::algebra::ArDifferential;
-use crate::algebra::{Alpha, Form, Index, MultiVector, Sign, Term, AR};
+use crate::algebra::{Alpha, Form, Index, MultiVector, Sign, Term, ALLOWED_ALPHA_FORMS, AR};
 
 macro_rules! __default_mvec_impls(
     {
@@ -76,6 +78,98 @@ macro_rules! __default_differential_operator_impls(
     }
 );
 
+/// Build a plane-wave style ansatz: each of the given forms is a unit magnitude term
+/// carrying the shared phase symbol as its Xi.
+///
+/// # Panics
+///
+/// Panics if any of the forms are not found in [`ALLOWED_ALPHA_FORMS`].
+///
+/// [`ALLOWED_ALPHA_FORMS`]: crate::algebra::ALLOWED_ALPHA_FORMS
+pub fn plane_wave_ansatz(forms: &[Form], phase_symbol: &str) -> MultiVector {
+    MultiVector::from_terms(
+        forms
+            .iter()
+            .map(|f| Term::new(Some(phase_symbol), Alpha::new(Sign::Pos, *f).unwrap()))
+            .collect(),
+    )
+}
+
+/// The general multivector with each of its 16 Xi values namespaced by prefix, i.e.
+/// `ξF_01` rather than `ξ01` for the prefix "F".
+pub fn general_multivector(prefix: &str) -> MultiVector {
+    FieldBuilder::new(prefix).over_forms(&ALLOWED_ALPHA_FORMS)
+}
+
+/// Builds symbolic fields whose Xi values are namespaced by a common prefix, keeping
+/// track of every symbol it has handed out. Use a separate builder for each field in a
+/// calculation so that unrelated fields never share a symbol.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FieldBuilder {
+    prefix: String,
+    symbols: HashSet<String>,
+}
+
+impl FieldBuilder {
+    pub fn new(prefix: &str) -> FieldBuilder {
+        FieldBuilder {
+            prefix: prefix.to_string(),
+            symbols: HashSet::new(),
+        }
+    }
+
+    /// A MultiVector with one term for each of the given forms
+    pub fn over_forms(&mut self, forms: &[Form]) -> MultiVector {
+        MultiVector::from_terms(
+            forms
+                .iter()
+                .map(|f| {
+                    let symbol = format!("{}_{}", self.prefix, f);
+                    let term = Term::new(Some(&symbol), Alpha::new(Sign::Pos, *f).unwrap());
+                    self.symbols.insert(symbol);
+                    term
+                })
+                .collect(),
+        )
+    }
+
+    /// The general multivector over all 16 forms
+    pub fn general(&mut self) -> MultiVector {
+        self.over_forms(&ALLOWED_ALPHA_FORMS)
+    }
+
+    /// Every symbol produced by this builder so far
+    pub fn symbols(&self) -> &HashSet<String> {
+        &self.symbols
+    }
+}
+
+/// True if the two MultiVectors have no Xi symbols in common
+pub fn disjoint_symbols(a: &MultiVector, b: &MultiVector) -> bool {
+    a.xi_symbols().is_disjoint(&b.xi_symbols())
+}
+
+/// The four coordinate basis vectors a0, a1, a2 and a3 as separate MultiVectors
+pub fn basis_vectors() -> [MultiVector; 4] {
+    Index::all().map(|i| {
+        let alpha = Alpha::new(Sign::Pos, Form::Vector(i)).unwrap();
+        MultiVector::from_terms(vec![Term::new(None, alpha)])
+    })
+}
+
+/// The reciprocal frame of the basis vectors a0, a1, a2 and a3: the vectors e^i satisfying
+/// `<e^i ^ e_j>0 = δ^i_j ap`. Under the +--- metric this flips the sign of the spatial
+/// vectors while leaving a0 unchanged.
+pub fn reciprocal_vectors() -> Vec<MultiVector> {
+    Index::all()
+        .iter()
+        .map(|i| {
+            let alpha = Alpha::new(Sign::Pos, Form::Vector(*i)).unwrap();
+            MultiVector::from_terms(vec![Term::new(None, alpha.inverse())])
+        })
+        .collect()
+}
+
 // snippets for building multivectors
 const _p: &'static str = "p";
 const _t: &'static str = "0";
@@ -131,3 +225,103 @@ __default_differential_operator_impls! {
     /// DG : differentiate with respect to all 16 elements of the algebra
     DG => ALLOWED_ALPHA_STRINGS.join(" ")
 }
+
+/// A differential operator over only the alphas of the given grade, e.g. the six
+/// bivector directions for grade 2.
+pub fn differential_over_grade(grade: usize) -> ArDifferential {
+    let alphas: Vec<Alpha> = ALLOWED_ALPHA_FORMS
+        .iter()
+        .filter(|f| f.grade() == grade)
+        .map(|f| Alpha::new(Sign::Pos, *f).unwrap())
+        .collect();
+
+    ArDifferential::new(&alphas)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::full;
+
+    #[test]
+    fn plane_wave_ansatz_attaches_phase_to_each_form() {
+        let forms: Vec<Form> = B().as_terms().iter().map(|t| t.form()).collect();
+        let ansatz = plane_wave_ansatz(&forms, "phase");
+
+        assert_eq!(
+            ansatz,
+            mvec![
+                term!("phase", 2 3),
+                term!("phase", 3 1),
+                term!("phase", 1 2)
+            ]
+        );
+        assert!(ansatz.iter().all(|t| t.magnitude() == 1));
+    }
+
+    #[test]
+    fn namespaced_fields_do_not_share_symbols() {
+        let mut f = FieldBuilder::new("F");
+        let mut g = FieldBuilder::new("G");
+        let (fm, gm) = (f.general(), g.general());
+
+        assert_eq!(&fm.xi_symbols(), f.symbols());
+        assert_eq!(fm, general_multivector("F"));
+        assert!(disjoint_symbols(&fm, &gm));
+        assert!(!disjoint_symbols(&fm, &general_multivector("F")));
+
+        let mut product: MultiVector = full(&fm, &gm);
+        product.simplify();
+        let mut unnamespaced: MultiVector = full(&G(), &G());
+        unnamespaced.simplify();
+
+        assert_eq!(product.as_terms().len(), 256);
+        assert!(unnamespaced.as_terms().len() < 256);
+    }
+
+    #[test]
+    fn basis_vectors_are_the_four_coordinate_vectors() {
+        for (ix, v) in Index::all().iter().zip(basis_vectors().iter()) {
+            assert_eq!(v.as_terms().len(), 1);
+            assert!(v.contains_grade(1));
+            assert!(v.contains_form(&Form::Vector(*ix)));
+        }
+    }
+
+    #[test]
+    fn reciprocal_vectors_are_dual_to_the_basis() {
+        let basis = [
+            mvec![term!(0)],
+            mvec![term!(1)],
+            mvec![term!(2)],
+            mvec![term!(3)],
+        ];
+
+        for (i, r) in reciprocal_vectors().iter().enumerate() {
+            for (j, e) in basis.iter().enumerate() {
+                let scalar: Vec<Alpha> =
+                    full::<_, _, MultiVector>(r, e).project_grade(0).as_alphas();
+
+                if i == j {
+                    assert_eq!(scalar, vec![Alpha::new(Sign::Pos, Form::Point).unwrap()]);
+                } else {
+                    assert_eq!(scalar, vec![]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn grade_restricted_differential_only_uses_that_grade() {
+        let d = differential_over_grade(2);
+        let res = d.left_apply(&mvec![term!("x", 0)]);
+        let mut partials: Vec<String> = res
+            .iter()
+            .map(|t| t.xi_str().trim_end_matches("ξx").to_string())
+            .collect();
+        partials.sort();
+
+        assert_eq!(res.as_terms().len(), 6);
+        assert_eq!(partials, vec!["∂01", "∂02", "∂03", "∂12", "∂23", "∂31"]);
+    }
+}
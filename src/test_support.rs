@@ -0,0 +1,150 @@
+//! Helpers for testing code built on top of arthroprod. This module is only compiled when
+//! the `test-support` feature is enabled (and for the crate's own tests).
+
+use crate::algebra::{full, Alpha, MultiVector, AR};
+
+/// Produce a human readable report of the terms that differ between two MultiVectors
+/// after simplification. Terms found only on the left are prefixed with '-' and those
+/// found only on the right are prefixed with '+'.
+pub fn mvec_diff(left: &MultiVector, right: &MultiVector) -> String {
+    let (mut l, mut r) = (left.clone(), right.clone());
+    l.simplify();
+    r.simplify();
+
+    let (lterms, rterms) = (l.as_terms(), r.as_terms());
+    let mut lines = vec![];
+    lines.extend(
+        lterms
+            .iter()
+            .filter(|t| !rterms.contains(t))
+            .map(|t| format!("  - {}", t)),
+    );
+    lines.extend(
+        rterms
+            .iter()
+            .filter(|t| !lterms.contains(t))
+            .map(|t| format!("  + {}", t)),
+    );
+
+    lines.join("\n")
+}
+
+/// Assert that a MultiVector survives being written out with [`MultiVector::to_json`] and
+/// read back in with [`MultiVector::from_json`] unchanged.
+pub fn assert_serde_roundtrip(m: &MultiVector) {
+    let json = m
+        .to_json()
+        .unwrap_or_else(|e| panic!("unable to serialize {}: {}", m, e));
+    let loaded = MultiVector::from_json(&json)
+        .unwrap_or_else(|e| panic!("unable to deserialize {}: {}", json, e));
+
+    assert_eq!(&loaded, m, "serde round trip failed for {}", json);
+}
+
+/// The Hermitian conjugate computed directly from its definition, `a0 ^ rev(M) ^ a0`
+/// formed term by term, for checking against [`AR::hermitian`].
+pub fn hermitian_via_sandwich(m: &MultiVector) -> MultiVector {
+    let a0 = alpha!(0);
+
+    m.iter()
+        .map(|t| {
+            let mut res = t.reversed();
+            let left: Alpha = full(&a0, &res.alpha());
+            res.set_alpha(full(&left, &a0));
+            res
+        })
+        .collect()
+}
+
+/// Assert that two MultiVectors are equal once both have been simplified. On failure the
+/// panic message includes both simplified values and a term by term diff of the two.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate arthroprod; fn main() {
+/// use arthroprod::algebra::*;
+///
+/// assert_mvec_eq!(mvec![term!(1), term!(1)], mvec![term!(1) * 2isize]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_mvec_eq(
+    ($left:expr, $right:expr) => {
+        {
+            let mut left: $crate::algebra::MultiVector = $left.clone();
+            let mut right: $crate::algebra::MultiVector = $right.clone();
+            left.simplify();
+            right.simplify();
+
+            if left != right {
+                panic!(
+                    "assertion failed: `(left == right)` after simplification\n  left: {}\n right: {}\ndiff:\n{}",
+                    left,
+                    right,
+                    $crate::test_support::mvec_diff(&left, &right)
+                );
+            }
+        }
+    };
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_serde_roundtrip, hermitian_via_sandwich};
+    use crate::algebra::*;
+    use crate::prelude::{Fields, Odd_sub_algebra, DG, G};
+
+    #[test]
+    fn assert_mvec_eq_passes_when_equal_after_simplify() {
+        assert_mvec_eq!(
+            mvec![term!(1), term!(0 2), term!(1)],
+            mvec![term!(0 2), term!(1) * 2isize]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "- +a1(ξ1)")]
+    fn assert_mvec_eq_panics_with_diff_when_not_equal() {
+        assert_mvec_eq!(mvec![term!(1), term!(0 2)], mvec![term!(0 2), term!(2 3)]);
+    }
+
+    #[test]
+    fn serde_roundtrip_preserves_partials_and_quotients() {
+        let field = mvec![term!("a", 2 3), term!(["b", "c"], 0 1), -term!("d", 1)];
+        let with_partials = DG().left_apply(&field);
+        let quotients: MultiVector = div(&term!("e", 0 2), &term!(["f", "g"], 1));
+
+        assert_serde_roundtrip(&field);
+        assert_serde_roundtrip(&with_partials);
+        assert!(quotients.to_string().contains('/'));
+        assert_serde_roundtrip(&quotients);
+    }
+
+    #[test]
+    fn serde_roundtrip_holds_for_random_multivectors() {
+        for seed in 0..50 {
+            let m = MultiVector::random(12, seed);
+            let product: MultiVector = full(&m, &m.hermitian());
+
+            assert_serde_roundtrip(&m);
+            assert_serde_roundtrip(&product);
+        }
+    }
+
+    #[test]
+    fn hermitian_matches_the_sandwich_definition() {
+        for form in ALLOWED_ALPHA_FORMS.iter() {
+            let blade = MultiVector::from_terms(vec![Term::new(
+                None,
+                Alpha::new(Sign::Pos, *form).unwrap(),
+            )]);
+            assert_eq!(hermitian_via_sandwich(&blade), blade.hermitian());
+        }
+
+        let m = mvec![term!("a", 1) * 2usize, -term!("b", 0 2), term!(0 1 2 3)];
+        for m in [G(), Fields(), Odd_sub_algebra(), m].iter() {
+            assert_eq!(hermitian_via_sandwich(m), m.hermitian());
+        }
+    }
+}